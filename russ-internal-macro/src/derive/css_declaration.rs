@@ -1,16 +1,47 @@
+use super::args::{self, Args, FromArgs};
 use heck::KebabCase;
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::DeriveInput;
+use quote::{quote, ToTokens};
+use syn::{Attribute, DeriveInput, LitStr};
+
+struct DeclarationAttr {
+    attr: Attribute,
+    name: Option<LitStr>,
+}
+impl FromArgs for DeclarationAttr {
+    fn attr_path() -> &'static str {
+        "declaration"
+    }
+
+    fn from_args(attr: Attribute, args: &Args) -> syn::Result<Self> {
+        Ok(Self {
+            attr,
+            name: args.get_kwarg_str("name")?,
+        })
+    }
+}
+impl ToTokens for DeclarationAttr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.attr.to_tokens(tokens)
+    }
+}
 
 pub fn generate_write_declaration(input: DeriveInput) -> syn::Result<TokenStream> {
+    let attr: Option<DeclarationAttr> = args::parse_single_from_attrs(&input.attrs).transpose()?;
     let name_ident = input.ident;
-    let property_name_str = name_ident.to_string().to_kebab_case();
+    let property_name_str = attr.and_then(|attr| attr.name).map_or_else(
+        || name_ident.to_string().to_kebab_case(),
+        |name| name.value(),
+    );
 
     Ok(quote! {
+        impl ::russ_internal::CssProperty for #name_ident {
+            const PROPERTY_NAME: &'static str = #property_name_str;
+        }
+
         impl ::russ_internal::WriteDeclaration for #name_ident {
             fn write_property(&self, f: &mut ::russ_internal::CssWriter) -> ::russ_internal::WriteResult {
-                f.write_str(#property_name_str)
+                f.write_str(<Self as ::russ_internal::CssProperty>::PROPERTY_NAME)
             }
         }
     })
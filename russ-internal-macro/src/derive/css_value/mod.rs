@@ -84,12 +84,10 @@ fn generate_write_for_fields_tokens(
                         .unit
                         .as_ref()
                         .map_or_else(|| container_ident.to_string().to_lowercase(), LitStr::value);
-                    let write_value = css_fields.first().unwrap().gen_write()?;
+                    let bind_ident = &css_fields.first().unwrap().bind_ident;
 
                     quote! {
-                        use ::std::io::Write;
-                        #write_value
-                        f.write_str(#unit_str)
+                        ::russ_internal::WriteValue::write_dimension(#bind_ident, f, #unit_str)
                     }
                 }
             }
@@ -36,14 +36,11 @@ fn generate_variant_constructor_fn(variant: &Variant) -> syn::Result<TokenStream
         return Ok(quote! {});
     }
 
-    let variant_ident_str = arg
+    let variant_ident = &variant.ident;
+    let fn_ident_str = arg
         .and_then(|arg| arg.name)
-        .map_or_else(|| variant.ident.to_string(), |name| name.value());
-    let variant_ident: Ident = syn::parse_str(&variant_ident_str)?;
-    let fn_ident = Ident::new(
-        &variant_ident.to_string().to_snake_case(),
-        variant_ident.span(),
-    );
+        .map_or_else(|| variant_ident.to_string(), |name| name.value());
+    let fn_ident = Ident::new(&fn_ident_str.to_snake_case(), variant_ident.span());
 
     let is_named = matches!(variant.fields, Fields::Named(_));
 
@@ -16,7 +16,7 @@ pub fn derive_value(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     to_token_stream(derive::generate_write_value(input))
 }
 
-#[proc_macro_derive(CssDeclaration)]
+#[proc_macro_derive(CssDeclaration, attributes(declaration))]
 pub fn derive_declaration(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     to_token_stream(derive::generate_write_declaration(input))
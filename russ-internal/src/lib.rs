@@ -5,12 +5,113 @@ use std::io::{self, Write};
 
 pub type WriteResult<T = ()> = io::Result<T>;
 
+/// Options controlling how values are written.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct WriterOptions {
+    /// Maximum number of fractional digits written for numbers.
+    pub precision: usize,
+    /// Omit characters which aren't required, like the leading zero in `0.5`.
+    pub minify: bool,
+}
+impl Default for WriterOptions {
+    fn default() -> Self {
+        Self {
+            precision: 6,
+            minify: false,
+        }
+    }
+}
+impl WriterOptions {
+    /// Number as it is written with these options.
+    /// All numbers which are written identically are rounded to the same value.
+    pub fn round_number(&self, v: f64) -> f64 {
+        if v.is_finite() {
+            format_number(v, self).parse().unwrap_or(v)
+        } else {
+            v
+        }
+    }
+}
+
+/// Format a finite number according to the options.
+fn format_number(v: f64, options: &WriterOptions) -> String {
+    // numbers this large would be written with all of their digits otherwise
+    let mut s = if v.abs() >= 1e21 {
+        let s = format!("{:.*e}", options.precision, v);
+        let (mantissa, exponent) = s.split_at(s.find('e').unwrap_or(s.len()));
+        format!("{}{}", trim_fraction(mantissa), exponent)
+    } else {
+        trim_fraction(&format!("{:.*}", options.precision, v)).to_owned()
+    };
+
+    if s == "-0" {
+        s.remove(0);
+    }
+    if options.minify {
+        if s.starts_with("0.") {
+            s.remove(0);
+        } else if s.starts_with("-0.") {
+            s.remove(1);
+        }
+    }
+    s
+}
+
+fn trim_fraction(s: &str) -> &str {
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        s
+    }
+}
+
 pub struct CssWriter<'a> {
     buf: &'a mut (dyn Write + 'a),
+    options: WriterOptions,
 }
 impl<'a> CssWriter<'a> {
     pub fn new(buf: &'a mut (dyn Write + 'a)) -> Self {
-        Self { buf }
+        Self::with_options(buf, WriterOptions::default())
+    }
+
+    pub fn with_options(buf: &'a mut (dyn Write + 'a), options: WriterOptions) -> Self {
+        Self { buf, options }
+    }
+
+    pub fn options(&self) -> &WriterOptions {
+        &self.options
+    }
+
+    /// Write a number.
+    ///
+    /// Non-finite numbers are written using the `calc()` constants like `calc(infinity)`.
+    pub fn write_number(&mut self, v: f64) -> WriteResult {
+        self.write_dimension(v, "")
+    }
+
+    /// Write a number followed by a unit.
+    ///
+    /// Non-finite numbers are written like `calc(infinity * 1px)`.
+    pub fn write_dimension(&mut self, v: f64, unit: &str) -> WriteResult {
+        if v.is_finite() {
+            let s = format_number(v, &self.options);
+            self.write_str(&s)?;
+            return self.write_str(unit);
+        }
+
+        self.write_str("calc(")?;
+        self.write_str(if v.is_nan() {
+            "NaN"
+        } else if v.is_sign_positive() {
+            "infinity"
+        } else {
+            "-infinity"
+        })?;
+        if !unit.is_empty() {
+            self.write_str(" * 1")?;
+            self.write_str(unit)?;
+        }
+        self.write_char(')')
     }
 
     pub fn write_char(&mut self, c: char) -> WriteResult {
@@ -33,6 +134,19 @@ impl Write for CssWriter<'_> {
 
 pub trait WriteValue {
     fn write_value(&self, f: &mut CssWriter) -> WriteResult;
+
+    /// Write the value followed by a unit.
+    /// Used by dimensions.
+    fn write_dimension(&self, f: &mut CssWriter, unit: &str) -> WriteResult {
+        self.write_value(f)?;
+        f.write_str(unit)
+    }
+}
+
+/// Property whose name is known without an instance.
+/// Implemented by the [`CssDeclaration`] derive.
+pub trait CssProperty {
+    const PROPERTY_NAME: &'static str;
 }
 
 pub trait WriteDeclaration: WriteValue {
@@ -0,0 +1,13 @@
+use crate::values::{Angle, Length, LengthPercentage, Number, Percentage};
+use russ_internal_macro::vds;
+
+// https://www.w3.org/TR/css-transforms-2/#individual-transforms
+
+vds! {
+    <"translate"> = <transform-none> | <length-percentage> [ <length-percentage> <length>? ]?;
+    <transform-none> = none;
+}
+
+vds! { <"rotate"> = <transform-none> | <angle> | [ x | y | z | <number>{3} ] && <angle>; }
+
+vds! { <"scale"> = <transform-none> | [ <number> | <percentage> ]{1,3}; }
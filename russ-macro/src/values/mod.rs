@@ -17,9 +17,11 @@ mod textual;
 pub use numeric::{Integer, Number, Percentage};
 pub use textual::{CssString, CustomIdent, Url, UrlModifier};
 
+pub type Angle = String;
 pub type Color = String;
-pub type LengthPercentage = String;
 pub type Image = String;
+pub type Length = String;
+pub type LengthPercentage = String;
 
 pub enum ValueDiscriminant {
     CustomIdent,
@@ -35,6 +35,51 @@ pub fn add_style_sheet(id: &str, body: &str) -> bool {
     true
 }
 
+/// Attribute marking style sheets added by [`add_style_sheet_first`].
+const FIRST_ATTR: &str = "data-russ-first";
+
+/// Add a style sheet to the start of the head element so that it precedes all other style sheets.
+/// Style sheets added this way keep the order in which they were added.
+pub fn add_style_sheet_first(id: &str, body: &str) -> bool {
+    let doc = get_document();
+    if get_style_el(&doc, id).is_some() {
+        // already exists
+        return false;
+    }
+
+    let el = build_style_element(&doc, id, body);
+    el.set_attribute(FIRST_ATTR, "")
+        .expect("failed to mark style element");
+    let head = doc.head().expect("document has no head");
+
+    let mut last_first = None;
+    let mut child = head.first_element_child();
+    while let Some(current) = child {
+        child = current.next_element_sibling();
+        if current.has_attribute(FIRST_ATTR) {
+            last_first = Some(current);
+        }
+    }
+
+    if let Some(last_first) = last_first {
+        last_first.after_with_node_1(&el)
+    } else {
+        head.prepend_with_node_1(&el)
+    }
+    .expect("failed to add style to head element");
+    true
+}
+
+/// Replace the body of an existing style sheet.
+pub fn update_style_sheet(id: &str, body: &str) -> bool {
+    if let Some(el) = get_style_el(&get_document(), id) {
+        el.set_inner_html(body);
+        true
+    } else {
+        false
+    }
+}
+
 /// Check if there is a style sheet with the given id.
 pub fn has_style_sheet(id: &str) -> bool {
     get_style_el(&get_document(), id).is_some()
@@ -3,8 +3,8 @@
 pub mod props;
 pub mod values;
 pub use russ_internal::{
-    multiple, vec_into, CssDeclaration, CssValue, CssWriter, WriteDeclaration, WriteResult,
-    WriteValue,
+    multiple, vec_into, CssDeclaration, CssProperty, CssValue, CssWriter, WriteDeclaration,
+    WriteResult, WriteValue, WriterOptions,
 };
 use std::{iter, ops::Deref};
 
@@ -70,17 +70,17 @@ impl<T> Multiple<T> {
         }
     }
 
-    fn first(&self) -> &T {
+    pub(crate) fn first(&self) -> &T {
         // SAFETY: type is guaranteed to have at least one item
         unsafe { self.0.get_unchecked(0) }
     }
 
-    fn rest(&self) -> &[T] {
+    pub(crate) fn rest(&self) -> &[T] {
         // SAFETY: type is guaranteed to have at least one item
         unsafe { self.0.get_unchecked(1..) }
     }
 
-    fn split_first(&self) -> (&T, &[T]) {
+    pub(crate) fn split_first(&self) -> (&T, &[T]) {
         (self.first(), self.rest())
     }
 }
@@ -106,6 +106,14 @@ impl<T> Deref for Multiple<T> {
         &self.0
     }
 }
+impl<'a, T> IntoIterator for &'a Multiple<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
 impl<T> From<T> for Multiple<T> {
     fn from(v: T) -> Self {
         Self::one(v)
@@ -0,0 +1,45 @@
+/// <https://drafts.csswg.org/css-animations-1>
+use crate::{
+    css::{
+        values::{EasingFunction, Number, Time},
+        Multiple,
+    },
+    KeyframesName,
+};
+use russ_internal::{CssDeclaration, CssValue, FromVariants};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum AnimationNameValue {
+    #[keyword]
+    None,
+    List(Multiple<KeyframesName>),
+}
+impl From<KeyframesName> for AnimationNameValue {
+    fn from(v: KeyframesName) -> Self {
+        Self::List(v.into())
+    }
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/animation-name>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub struct AnimationName(pub AnimationNameValue);
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/animation-duration>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub struct AnimationDuration(pub Multiple<Time>);
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/animation-timing-function>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub struct AnimationTimingFunction(pub Multiple<EasingFunction>);
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum SingleAnimationIterationCount {
+    #[keyword]
+    Infinite,
+    #[from_variant(into)]
+    Number(Number),
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/animation-iteration-count>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub struct AnimationIterationCount(pub Multiple<SingleAnimationIterationCount>);
@@ -0,0 +1,10 @@
+/// <https://drafts.csswg.org/css-color-4/#the-color-property>
+use crate::css::values::Color;
+use russ_internal::{CssDeclaration, CssValue};
+
+/// Foreground color of the text content.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/color>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+#[declaration(name = "color")]
+pub struct ForegroundColor(pub Color);
@@ -0,0 +1,54 @@
+/// <https://drafts.csswg.org/css-contain-3/#container-queries>
+use crate::css::values::CustomIdent;
+use russ_internal::{CssDeclaration, CssValue, CssWriter, WriteResult, WriteValue};
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/container-type>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub enum ContainerType {
+    #[keyword]
+    Normal,
+    #[keyword]
+    Size,
+    #[keyword]
+    InlineSize,
+}
+
+/// An empty list of names is written as `none`.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/container-name>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub struct ContainerName(#[field(write_fn = "Self::write_names")] pub Vec<CustomIdent>);
+impl ContainerName {
+    pub fn none() -> Self {
+        Self(Vec::new())
+    }
+
+    fn write_names(f: &mut CssWriter, names: &[CustomIdent]) -> WriteResult {
+        if let Some((first, others)) = names.split_first() {
+            first.write_value(f)?;
+            for name in others {
+                f.write_char(' ')?;
+                name.write_value(f)?;
+            }
+            Ok(())
+        } else {
+            f.write_str("none")
+        }
+    }
+}
+impl<T> From<T> for ContainerName
+where
+    T: Into<CustomIdent>,
+{
+    fn from(v: T) -> Self {
+        Self(vec![v.into()])
+    }
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/container>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+#[value]
+pub struct Container(
+    pub ContainerName,
+    #[field(option, prefix = "/ ")] pub Option<ContainerType>,
+);
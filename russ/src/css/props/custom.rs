@@ -0,0 +1,39 @@
+/// <https://drafts.csswg.org/css-variables/#defining-variables>
+use crate::css::values::{DashedIdent, Var};
+use russ_internal::{CssWriter, WriteDeclaration, WriteResult, WriteValue};
+
+/// Declaration of a custom property holding a value of type `T`.
+/// Use [`CustomProperty::var`] to reference it.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CustomProperty<T> {
+    pub name: DashedIdent,
+    pub value: T,
+}
+impl<T> CustomProperty<T> {
+    pub fn build(name: impl Into<DashedIdent>, value: impl Into<T>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn var(&self) -> Var<T> {
+        Var::new(self.name.clone())
+    }
+}
+impl<T> WriteValue for CustomProperty<T>
+where
+    T: WriteValue,
+{
+    fn write_value(&self, f: &mut CssWriter) -> WriteResult {
+        self.value.write_value(f)
+    }
+}
+impl<T> WriteDeclaration for CustomProperty<T>
+where
+    T: WriteValue,
+{
+    fn write_property(&self, f: &mut CssWriter) -> WriteResult {
+        self.name.write_value(f)
+    }
+}
@@ -0,0 +1,109 @@
+/// <https://drafts.csswg.org/css-fonts-4>
+use crate::css::{
+    values::{Angle, CssString, Number},
+    Multiple,
+};
+use russ_internal::{CssDeclaration, CssValue, CssWriter, FromVariants, WriteResult, WriteValue};
+
+/// <https://drafts.csswg.org/css-fonts-4/#family-name-value>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub struct FamilyName(pub CssString);
+impl<T> From<T> for FamilyName
+where
+    T: Into<CssString>,
+{
+    fn from(v: T) -> Self {
+        Self(v.into())
+    }
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#generic-family-value>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum GenericFamily {
+    #[keyword]
+    Serif,
+    #[keyword]
+    SansSerif,
+    #[keyword]
+    Cursive,
+    #[keyword]
+    Fantasy,
+    #[keyword]
+    Monospace,
+    #[keyword]
+    SystemUi,
+    #[keyword]
+    Emoji,
+    #[keyword]
+    Math,
+    #[keyword]
+    Fangsong,
+    #[keyword]
+    UiSerif,
+    #[keyword]
+    UiSansSerif,
+    #[keyword]
+    UiMonospace,
+    #[keyword]
+    UiRounded,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum FontFamilyItem {
+    #[from_variant(into)]
+    Name(FamilyName),
+    Generic(GenericFamily),
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/font-family>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub struct FontFamily(pub Multiple<FontFamilyItem>);
+
+/// <https://drafts.csswg.org/css-fonts-4/#font-weight-absolute-values>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum FontWeightAbsolute {
+    #[keyword]
+    Normal,
+    #[keyword]
+    Bold,
+    /// Must be in the range [1, 1000].
+    #[from_variant(into)]
+    Number(Number),
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum FontWeightValue {
+    Absolute(FontWeightAbsolute),
+    #[keyword]
+    Bolder,
+    #[keyword]
+    Lighter,
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/font-weight>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub struct FontWeight(pub FontWeightValue);
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum FontStyleValue {
+    #[keyword]
+    Normal,
+    #[keyword]
+    Italic,
+    #[value(write_fn = "Self::write_oblique")]
+    Oblique(Option<Angle>),
+}
+impl FontStyleValue {
+    fn write_oblique(f: &mut CssWriter, angle: &Option<Angle>) -> WriteResult {
+        f.write_str("oblique")?;
+        if let Some(angle) = angle {
+            f.write_char(' ')?;
+            angle.write_value(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/font-style>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub struct FontStyle(pub FontStyleValue);
@@ -1,8 +1,23 @@
+mod animation;
 mod background;
+mod color;
+mod contain;
+mod custom;
+mod font;
+mod transform;
+pub use animation::*;
 pub use background::*;
-use russ_internal::CssValue;
-
-// TODO find a way to integrate these globals cleanly.
+pub use color::*;
+pub use contain::*;
+pub use custom::*;
+pub use font::*;
+use russ_internal::{CssProperty, CssValue, CssWriter, WriteDeclaration, WriteResult, WriteValue};
+use std::{
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+pub use transform::*;
 
 // https://www.w3.org/TR/css-values-4/#css-wide-keywords
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
@@ -13,4 +28,90 @@ pub enum GlobalValue {
     Initial,
     #[keyword]
     Unset,
+    #[keyword]
+    Revert,
+    #[keyword]
+    RevertLayer,
+}
+
+/// Declaration of the property `P` with a CSS-wide keyword as its value.
+///
+/// See [`GlobalKeywords`] to create one.
+pub struct GlobalDeclaration<P> {
+    pub value: GlobalValue,
+    _property: PhantomData<fn() -> P>,
+}
+impl<P> GlobalDeclaration<P> {
+    pub fn new(value: GlobalValue) -> Self {
+        Self {
+            value,
+            _property: PhantomData,
+        }
+    }
+}
+impl<P> Clone for GlobalDeclaration<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<P> Copy for GlobalDeclaration<P> {}
+impl<P: CssProperty> Debug for GlobalDeclaration<P> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("GlobalDeclaration")
+            .field("property", &P::PROPERTY_NAME)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+impl<P> PartialEq for GlobalDeclaration<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+impl<P> Eq for GlobalDeclaration<P> {}
+impl<P: CssProperty> Hash for GlobalDeclaration<P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // the property needs to be part of the hash, otherwise `inherit` would produce the same key for every property.
+        P::PROPERTY_NAME.hash(state);
+        self.value.hash(state);
+    }
+}
+impl<P> WriteValue for GlobalDeclaration<P> {
+    fn write_value(&self, f: &mut CssWriter) -> WriteResult {
+        self.value.write_value(f)
+    }
+}
+impl<P: CssProperty> WriteDeclaration for GlobalDeclaration<P> {
+    fn write_property(&self, f: &mut CssWriter) -> WriteResult {
+        f.write_str(P::PROPERTY_NAME)
+    }
+}
+
+/// Constructors for declarations using a CSS-wide keyword.
+/// Implemented for every property.
+pub trait GlobalKeywords: CssProperty + Sized {
+    fn global(value: GlobalValue) -> GlobalDeclaration<Self> {
+        GlobalDeclaration::new(value)
+    }
+
+    fn inherit() -> GlobalDeclaration<Self> {
+        Self::global(GlobalValue::Inherit)
+    }
+
+    fn initial() -> GlobalDeclaration<Self> {
+        Self::global(GlobalValue::Initial)
+    }
+
+    fn unset() -> GlobalDeclaration<Self> {
+        Self::global(GlobalValue::Unset)
+    }
+
+    fn revert() -> GlobalDeclaration<Self> {
+        Self::global(GlobalValue::Revert)
+    }
+
+    fn revert_layer() -> GlobalDeclaration<Self> {
+        Self::global(GlobalValue::RevertLayer)
+    }
 }
+impl<P: CssProperty> GlobalKeywords for P {}
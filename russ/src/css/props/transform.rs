@@ -0,0 +1,155 @@
+/// <https://drafts.csswg.org/css-transforms-2>
+use crate::css::{
+    values::{
+        Angle, Length, LengthPercentage, Number, NumberPercentage, OriginPosition, Position,
+        TransformFunction,
+    },
+    Multiple,
+};
+use russ_internal::{CssDeclaration, CssValue, CssWriter, FromVariants, WriteResult, WriteValue};
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum TransformValue {
+    #[keyword]
+    None,
+    #[value]
+    List(#[field(iter, iter_separator = " ")] Multiple<TransformFunction>),
+}
+impl From<TransformFunction> for TransformValue {
+    fn from(v: TransformFunction) -> Self {
+        Self::List(v.into())
+    }
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/transform>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub struct Transform(pub TransformValue);
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/transform-origin>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+#[value]
+pub struct TransformOrigin(pub OriginPosition, pub Option<Length>);
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/transform-style>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub enum TransformStyle {
+    #[keyword]
+    Flat,
+    #[keyword(value = "preserve-3d")]
+    Preserve3d,
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/perspective>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue, FromVariants)]
+pub enum Perspective {
+    #[keyword]
+    None,
+    Length(Length),
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/perspective-origin>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub struct PerspectiveOrigin(pub Position);
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/translate>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub enum Translate {
+    #[keyword]
+    None,
+    #[value]
+    Offset(
+        LengthPercentage,
+        #[field(option, write_fn = "Self::write_yz")] Option<(LengthPercentage, Option<Length>)>,
+    ),
+}
+impl Translate {
+    pub fn x(x: impl Into<LengthPercentage>) -> Self {
+        Self::Offset(x.into(), None)
+    }
+
+    pub fn xy(x: impl Into<LengthPercentage>, y: impl Into<LengthPercentage>) -> Self {
+        Self::Offset(x.into(), Some((y.into(), None)))
+    }
+
+    pub fn xyz(
+        x: impl Into<LengthPercentage>,
+        y: impl Into<LengthPercentage>,
+        z: impl Into<Length>,
+    ) -> Self {
+        Self::Offset(x.into(), Some((y.into(), Some(z.into()))))
+    }
+
+    fn write_yz(f: &mut CssWriter, (y, z): &(LengthPercentage, Option<Length>)) -> WriteResult {
+        y.write_value(f)?;
+        if let Some(z) = z {
+            f.write_char(' ')?;
+            z.write_value(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum RotateAxis {
+    #[keyword]
+    X,
+    #[keyword]
+    Y,
+    #[keyword]
+    Z,
+    #[value]
+    Vector(Number, Number, Number),
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/rotate>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue, FromVariants)]
+pub enum Rotate {
+    #[keyword]
+    None,
+    Angle(Angle),
+    #[value]
+    Axis(RotateAxis, Angle),
+}
+
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/scale>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssDeclaration, CssValue)]
+pub enum Scale {
+    #[keyword]
+    None,
+    #[value]
+    Factors(
+        NumberPercentage,
+        #[field(option, write_fn = "Self::write_yz")]
+        Option<(NumberPercentage, Option<NumberPercentage>)>,
+    ),
+}
+impl Scale {
+    /// Scale both axes by the same factor.
+    pub fn uniform(v: impl Into<NumberPercentage>) -> Self {
+        Self::Factors(v.into(), None)
+    }
+
+    pub fn xy(x: impl Into<NumberPercentage>, y: impl Into<NumberPercentage>) -> Self {
+        Self::Factors(x.into(), Some((y.into(), None)))
+    }
+
+    pub fn xyz(
+        x: impl Into<NumberPercentage>,
+        y: impl Into<NumberPercentage>,
+        z: impl Into<NumberPercentage>,
+    ) -> Self {
+        Self::Factors(x.into(), Some((y.into(), Some(z.into()))))
+    }
+
+    fn write_yz(
+        f: &mut CssWriter,
+        (y, z): &(NumberPercentage, Option<NumberPercentage>),
+    ) -> WriteResult {
+        y.write_value(f)?;
+        if let Some(z) = z {
+            f.write_char(' ')?;
+            z.write_value(f)?;
+        }
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
-use super::Calc;
-use russ_internal::{CssValue, CssWriter, FromVariants, WriteResult, WriteValue};
+use super::{Calc, CalcDimension, CalcType, NumberFunction, Var};
+use russ_internal::{CssValue, CssWriter, FromVariants, WriteResult, WriteValue, WriterOptions};
 use std::{
     cmp::{Ordering, PartialEq, PartialOrd},
     hash::{Hash, Hasher},
@@ -14,6 +14,44 @@ impl WriteValue for CustomIdent {
         f.write_str(&self.0)
     }
 }
+impl<T> From<T> for CustomIdent
+where
+    T: Into<String>,
+{
+    fn from(v: T) -> Self {
+        Self(v.into())
+    }
+}
+
+/// Identifier starting with two dashes, used for custom properties.
+/// The dashes are added when the identifier is written and stripped when present in the given name.
+///
+/// <https://drafts.csswg.org/css-values-4/#dashed-idents>
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct DashedIdent(String);
+impl DashedIdent {
+    /// Name without the leading dashes.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+impl WriteValue for DashedIdent {
+    fn write_value(&self, f: &mut CssWriter) -> WriteResult {
+        write!(f, "--{}", self.0)
+    }
+}
+impl<T> From<T> for DashedIdent
+where
+    T: Into<String>,
+{
+    fn from(v: T) -> Self {
+        let name = v.into();
+        match name.strip_prefix("--") {
+            Some(stripped) => Self(stripped.to_owned()),
+            None => Self(name),
+        }
+    }
+}
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/string
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -39,7 +77,7 @@ pub type IntegerValueType = i32;
 pub enum Integer {
     #[from_variant(into)]
     Value(IntegerValueType),
-    Calc(Box<Calc>),
+    Calc(Box<Calc<Number>>),
 }
 impl WriteValue for Integer {
     fn write_value(&self, f: &mut CssWriter) -> WriteResult {
@@ -62,9 +100,15 @@ impl PartialOrd for Integer {
         }
     }
 }
-impl From<Calc> for Integer {
-    fn from(v: Calc) -> Self {
-        Self::Calc(Box::new(v))
+impl From<Calc<Number>> for Integer {
+    fn from(v: Calc<Number>) -> Self {
+        match v.into_value() {
+            Number::Value(v) if v.fract() == 0.0 && v.abs() <= IntegerValueType::MAX as f64 => {
+                Self::Value(v as IntegerValueType)
+            }
+            Number::Calc(calc) => Self::Calc(calc),
+            v => Self::Calc(Box::new(Calc::unary(v))),
+        }
     }
 }
 
@@ -75,13 +119,40 @@ pub type NumberValueType = f64;
 pub enum Number {
     #[from_variant(into)]
     Value(NumberValueType),
-    Calc(Box<Calc>),
+    Calc(Box<Calc<Number>>),
+    Function(Box<NumberFunction>),
+    Var(Box<Var<Number>>),
+}
+impl Number {
+    /// Value of the number if it's known without evaluating a `calc()` or `var()`.
+    pub fn value(&self) -> Option<NumberValueType> {
+        match self {
+            Self::Value(v) => Some(*v),
+            _ => None,
+        }
+    }
 }
 impl WriteValue for Number {
     fn write_value(&self, f: &mut CssWriter) -> WriteResult {
         match self {
-            Self::Value(v) => write!(f, "{}", v),
+            Self::Value(v) => f.write_number(*v),
             Self::Calc(calc) => calc.write_value(f),
+            Self::Function(function) => function.write_value(f),
+            Self::Var(var) => var.write_value(f),
+        }
+    }
+
+    fn write_dimension(&self, f: &mut CssWriter, unit: &str) -> WriteResult {
+        match self {
+            Self::Value(v) => f.write_dimension(*v, unit),
+            // anything else has to be multiplied with the unit
+            _ => {
+                f.write_str("calc(")?;
+                self.write_value(f)?;
+                f.write_str(" * 1")?;
+                f.write_str(unit)?;
+                f.write_char(')')
+            }
         }
     }
 }
@@ -90,21 +161,40 @@ impl Default for Number {
         Self::Value(Default::default())
     }
 }
+/// Value as it is written with the default options, with all `NaN`s collapsed
+/// into one representation, so that values which render identically like
+/// `0.1 + 0.2` and `0.3` compare equal.
+fn canonical(v: NumberValueType) -> NumberValueType {
+    let v = WriterOptions::default().round_number(v);
+    if v.is_nan() {
+        NumberValueType::NAN
+    } else {
+        v
+    }
+}
+
+fn canonical_bits(v: NumberValueType) -> u64 {
+    canonical(v).to_bits()
+}
+
 impl Eq for Number {}
 impl Hash for Number {
     fn hash<H: Hasher>(&self, state: &mut H) {
         match self {
-            Self::Value(v) => v.to_bits().hash(state),
+            Self::Value(v) => canonical_bits(*v).hash(state),
             Self::Calc(v) => v.hash(state),
+            Self::Function(v) => v.hash(state),
+            Self::Var(v) => v.hash(state),
         }
     }
 }
 impl PartialEq for Number {
     fn eq(&self, other: &Self) -> bool {
         match (self, other) {
-            // TODO find a method that guarantees that s == o => render(s) == render(o)
-            (Self::Value(s), Self::Value(o)) => s.eq(o),
+            (Self::Value(s), Self::Value(o)) => canonical_bits(*s) == canonical_bits(*o),
             (Self::Calc(s), Self::Calc(o)) => s.eq(o),
+            (Self::Function(s), Self::Function(o)) => s.eq(o),
+            (Self::Var(s), Self::Var(o)) => s.eq(o),
             _ => false,
         }
     }
@@ -112,14 +202,64 @@ impl PartialEq for Number {
 impl PartialOrd for Number {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match (self, other) {
-            (Self::Value(s), Self::Value(o)) => s.partial_cmp(o),
+            (Self::Value(s), Self::Value(o)) => {
+                if canonical_bits(*s) == canonical_bits(*o) {
+                    Some(Ordering::Equal)
+                } else {
+                    canonical(*s).partial_cmp(&canonical(*o))
+                }
+            }
             _ => None,
         }
     }
 }
-impl From<Calc> for Number {
-    fn from(v: Calc) -> Self {
-        Self::Calc(Box::new(v))
+impl CalcType for Number {
+    fn to_calc_dimension(&self) -> Option<CalcDimension> {
+        match self {
+            Self::Function(function) => function.resolve(),
+            _ => self.value(),
+        }
+        .map(|v| ("", v))
+    }
+
+    fn from_calc_dimension((unit, v): CalcDimension) -> Option<Self> {
+        match unit {
+            "" => Some(Self::Value(v)),
+            _ => None,
+        }
+    }
+
+    fn to_calc(&self) -> Option<Calc<Self>> {
+        match self {
+            Self::Calc(calc) => Some((**calc).clone()),
+            _ => None,
+        }
+    }
+
+    fn from_calc(calc: Calc<Self>) -> Self {
+        Self::Calc(Box::new(calc))
+    }
+}
+impl From<Calc<Number>> for Number {
+    fn from(v: Calc<Number>) -> Self {
+        v.into_value()
+    }
+}
+impl From<NumberFunction> for Number {
+    fn from(v: NumberFunction) -> Self {
+        match v.resolve() {
+            Some(v) => Self::Value(v),
+            // constants can only be used inside of math functions
+            None if matches!(v, NumberFunction::Constant(_)) => {
+                Self::Calc(Box::new(Calc::unary(Self::Function(Box::new(v)))))
+            }
+            None => Self::Function(Box::new(v)),
+        }
+    }
+}
+impl From<Var<Number>> for Number {
+    fn from(v: Var<Number>) -> Self {
+        Self::Var(Box::new(v))
     }
 }
 
@@ -143,11 +283,61 @@ where
     }
 }
 
-// TODO manual eq, ord implementation so that 16/4 == 4/1
 // https://developer.mozilla.org/en-US/docs/Web/CSS/ratio
-#[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, CssValue)]
+#[derive(Clone, Debug, CssValue)]
 #[value(separator = "/")]
 pub struct Ratio(pub Integer, pub Integer);
+impl Ratio {
+    /// Ratio in lowest terms, `16/4` becomes `4/1`.
+    ///
+    /// Ratios containing a `calc()` expression are returned as is.
+    pub fn reduced(&self) -> Self {
+        match (&self.0, &self.1) {
+            (Integer::Value(w), Integer::Value(h)) => {
+                let (w, h) = (i64::from(*w), i64::from(*h));
+                let divisor = gcd(w, h);
+                if divisor > 1 {
+                    return Self(
+                        Integer::Value((w / divisor) as IntegerValueType),
+                        Integer::Value((h / divisor) as IntegerValueType),
+                    );
+                }
+                self.clone()
+            }
+            _ => self.clone(),
+        }
+    }
+}
+impl Eq for Ratio {}
+impl Hash for Ratio {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self(w, h) = self.reduced();
+        w.hash(state);
+        h.hash(state);
+    }
+}
+impl PartialEq for Ratio {
+    fn eq(&self, other: &Self) -> bool {
+        let (Self(sw, sh), Self(ow, oh)) = (self.reduced(), other.reduced());
+        sw == ow && sh == oh
+    }
+}
+impl PartialOrd for Ratio {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        match (self, other) {
+            (
+                Self(Integer::Value(sw), Integer::Value(sh)),
+                Self(Integer::Value(ow), Integer::Value(oh)),
+            ) if *sh > 0 && *oh > 0 => {
+                (i64::from(*sw) * i64::from(*oh)).partial_cmp(&(i64::from(*ow) * i64::from(*sh)))
+            }
+            _ => None,
+        }
+    }
+}
 impl<W, H> From<(W, H)> for Ratio
 where
     W: Into<Integer>,
@@ -158,6 +348,15 @@ where
     }
 }
 
+fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a.abs()
+}
+
 // https://developer.mozilla.org/en-US/docs/Web/CSS/url
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, CssValue)]
 #[function]
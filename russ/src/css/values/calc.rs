@@ -1,65 +1,195 @@
 use super::{
-    Angle, Flex, Frequency, Length, Number, NumberValueType, Percentage, Resolution, Time,
+    Angle, AnglePercentage, Frequency, FrequencyPercentage, Length, LengthPercentage, MathConstant,
+    MathFunction, Number, NumberFunction, NumberValueType, Percentage, Time, TimePercentage,
 };
-use russ_internal::{CssValue, CssWriter, FromVariants, WriteResult, WriteValue};
+use russ_internal::{CssValue, CssWriter, WriteResult, WriteValue};
 
-// TODO perhaps use Calc<T> where T is a dimension (Angle, Length) so that CalcValue only allows that particular dimension
-//      Need to verify if this is actually valid though.
+/// Unit and value of a plain dimension inside of a `calc()` expression.
+///
+/// Numbers use an empty unit.
+pub type CalcDimension = (&'static str, NumberValueType);
+
+/// Types a `calc()` expression can resolve to.
+///
+/// Percentages can only be mixed with dimensions in the combined types like [`LengthPercentage`].
+pub trait CalcType: WriteValue + Clone {
+    /// Split the value into its unit and value if it's a plain dimension.
+    fn to_calc_dimension(&self) -> Option<CalcDimension>;
+    /// Create a value from a dimension returned by [`CalcType::to_calc_dimension`] or [`CalcType::canonicalize`].
+    fn from_calc_dimension(dimension: CalcDimension) -> Option<Self>;
+    /// Convert an absolute unit to the canonical unit of the type, `1in` becomes `96px`.
+    /// Units which can't be converted are returned as is.
+    fn canonicalize(dimension: CalcDimension) -> CalcDimension {
+        dimension
+    }
+    /// Expression of the value if it's a `calc()` itself.
+    fn to_calc(&self) -> Option<Calc<Self>>;
+    /// Wrap the expression without simplifying it.
+    fn from_calc(calc: Calc<Self>) -> Self;
+
+    /// Whether both values describe the same dimension, even if they use different units.
+    ///
+    /// Unlike `==` this treats `1in` and `96px` as equal.
+    /// Values that can't be converted to a canonical unit fall back to `==`.
+    fn is_equivalent(&self, other: &Self) -> bool
+    where
+        Self: PartialEq,
+    {
+        if self == other {
+            return true;
+        }
+        let canonical = |v: &Self| v.to_calc_dimension().map(Self::canonicalize);
+        match (canonical(self), canonical(other)) {
+            // conversion factors aren't exact, so allow for rounding errors
+            (Some((s_unit, s)), Some((o_unit, o))) => {
+                s_unit == o_unit && (s - o).abs() <= 1e-9 * s.abs().max(o.abs())
+            }
+            _ => false,
+        }
+    }
+}
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/calc
-#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
-#[function]
-pub struct Calc(CalcSum);
-impl Calc {
-    pub fn unary(value: impl Into<CalcSum>) -> Self {
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Calc<T>(CalcSum<T>);
+impl<T> Calc<T> {
+    pub fn unary(value: impl Into<CalcSum<T>>) -> Self {
         Self(value.into())
     }
 
-    fn bin_sum(a: impl Into<CalcProduct>, b: CalcSumPart) -> Self {
+    fn bin_sum(a: impl Into<CalcProduct<T>>, b: CalcSumPart<T>) -> Self {
         Self::unary(CalcSum::binary(a, b))
     }
 
-    pub fn bin_add(a: impl Into<CalcProduct>, b: impl Into<CalcProduct>) -> Self {
+    pub fn bin_add(a: impl Into<CalcProduct<T>>, b: impl Into<CalcProduct<T>>) -> Self {
         Self::bin_sum(a, CalcSumPart::Add(b.into()))
     }
 
-    pub fn bin_sub(a: impl Into<CalcProduct>, b: impl Into<CalcProduct>) -> Self {
+    pub fn bin_sub(a: impl Into<CalcProduct<T>>, b: impl Into<CalcProduct<T>>) -> Self {
         Self::bin_sum(a, CalcSumPart::Sub(b.into()))
     }
 
-    fn bin_product(a: impl Into<CalcValue>, b: CalcProductPart) -> Self {
+    fn bin_product(a: impl Into<CalcValue<T>>, b: CalcProductPart) -> Self {
         Self::unary(CalcProduct::binary(a, b))
     }
 
-    pub fn bin_mul(a: impl Into<CalcValue>, b: impl Into<CalcValue>) -> Self {
+    pub fn bin_mul(a: impl Into<CalcValue<T>>, b: impl Into<CalcValue<Number>>) -> Self {
         Self::bin_product(a, CalcProductPart::Mul(b.into()))
     }
 
-    pub fn bin_div(a: impl Into<CalcValue>, b: impl Into<Number>) -> Self {
+    pub fn bin_div(a: impl Into<CalcValue<T>>, b: impl Into<CalcValue<Number>>) -> Self {
         Self::bin_product(a, CalcProductPart::Div(b.into()))
     }
+
+    pub(crate) fn map<U>(&self, f: &impl Fn(&T) -> U) -> Calc<U> {
+        Calc(self.0.map(f))
+    }
+
+    pub(crate) fn as_sum(&self) -> &CalcSum<T> {
+        &self.0
+    }
+
+    pub(crate) fn into_sum(self) -> CalcSum<T> {
+        self.0
+    }
+
+    pub(crate) fn push_sum(mut self, part: CalcSumPart<T>) -> Self {
+        (self.0).1.push(part);
+        self
+    }
+
+    pub(crate) fn push_product(self, part: CalcProductPart) -> Self {
+        match self.0 {
+            CalcSum(mut product, parts) if parts.is_empty() => {
+                product.1.push(part);
+                Self(CalcSum(product, parts))
+            }
+            sum => Self::bin_product(Self(sum), part),
+        }
+    }
+}
+impl<T: CalcType> Calc<T> {
+    /// Simplify the expression following the rules of CSS Values 4.
+    ///
+    /// Nested `calc()`s and parentheses are unwrapped, numeric factors are folded into the terms and dimensions are
+    /// combined, converting them to their canonical unit if they don't share the same one.
+    /// The terms are sorted with the number first, followed by the percentage and the dimensions ordered by unit.
+    pub fn simplify(&self) -> Self {
+        Self(self.0.simplify())
+    }
+
+    /// Simplify the expression and return the plain dimension if it resolves to one.
+    pub fn into_value(self) -> T {
+        let terms = simplify_sum(&self.0);
+        if let Some(value) = terms.dimension().and_then(T::from_calc_dimension) {
+            return value;
+        }
+        T::from_calc(Self(terms.into_sum()))
+    }
+}
+impl<T: WriteValue> WriteValue for Calc<T> {
+    fn write_value(&self, f: &mut CssWriter) -> WriteResult {
+        // math functions don't need to be wrapped in a `calc()`
+        if let CalcSum(CalcProduct(CalcValue::Function(function), factors), parts) = &self.0 {
+            if factors.is_empty() && parts.is_empty() {
+                return function.write_value(f);
+            }
+        }
+
+        f.write_str("calc(")?;
+        self.0.write_value(f)?;
+        f.write_char(')')
+    }
+}
+impl<T> From<MathFunction<T>> for Calc<T> {
+    fn from(v: MathFunction<T>) -> Self {
+        Self::unary(v)
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
-pub enum CalcSumPart {
+pub enum CalcSumPart<T> {
     #[value(prefix = " + ")]
-    Add(CalcProduct),
+    Add(CalcProduct<T>),
     #[value(prefix = " - ")]
-    Sub(CalcProduct),
+    Sub(CalcProduct<T>),
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct CalcSum(pub CalcProduct, pub Vec<CalcSumPart>);
-impl CalcSum {
-    pub fn unary(value: impl Into<CalcProduct>) -> Self {
+pub struct CalcSum<T>(pub CalcProduct<T>, pub Vec<CalcSumPart<T>>);
+impl<T> CalcSum<T> {
+    pub fn unary(value: impl Into<CalcProduct<T>>) -> Self {
         Self(value.into(), Vec::new())
     }
 
-    fn binary(a: impl Into<CalcProduct>, b: CalcSumPart) -> Self {
+    fn binary(a: impl Into<CalcProduct<T>>, b: CalcSumPart<T>) -> Self {
         Self(a.into(), vec![b])
     }
+
+    pub(crate) fn map<U>(&self, f: &impl Fn(&T) -> U) -> CalcSum<U> {
+        CalcSum(
+            self.0.map(f),
+            self.1
+                .iter()
+                .map(|part| match part {
+                    CalcSumPart::Add(v) => CalcSumPart::Add(v.map(f)),
+                    CalcSumPart::Sub(v) => CalcSumPart::Sub(v.map(f)),
+                })
+                .collect(),
+        )
+    }
+}
+impl<T: CalcType> CalcSum<T> {
+    pub(crate) fn simplify(&self) -> Self {
+        simplify_sum(self).into_sum()
+    }
+
+    /// Dimension the sum resolves to if it can be simplified to a single one.
+    pub(crate) fn to_dimension(&self) -> Option<CalcDimension> {
+        simplify_sum(self).dimension()
+    }
 }
-impl WriteValue for CalcSum {
+impl<T: WriteValue> WriteValue for CalcSum<T> {
     fn write_value(&self, f: &mut CssWriter) -> WriteResult {
         self.0.write_value(f)?;
         for part in &self.1 {
@@ -68,35 +198,40 @@ impl WriteValue for CalcSum {
         Ok(())
     }
 }
-impl<T> From<T> for CalcSum
+impl<T, V> From<V> for CalcSum<T>
 where
-    T: Into<CalcProduct>,
+    V: Into<CalcProduct<T>>,
 {
-    fn from(v: T) -> Self {
+    fn from(v: V) -> Self {
         Self::unary(v)
     }
 }
 
+/// Factors in a product are always numbers so that the product keeps the type of its first value.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
 pub enum CalcProductPart {
     #[value(prefix = " * ")]
-    Mul(CalcValue),
+    Mul(CalcValue<Number>),
     #[value(prefix = " / ")]
-    Div(Number),
+    Div(CalcValue<Number>),
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct CalcProduct(pub CalcValue, pub Vec<CalcProductPart>);
-impl CalcProduct {
-    pub fn unary(value: impl Into<CalcValue>) -> Self {
+pub struct CalcProduct<T>(pub CalcValue<T>, pub Vec<CalcProductPart>);
+impl<T> CalcProduct<T> {
+    pub fn unary(value: impl Into<CalcValue<T>>) -> Self {
         Self(value.into(), Vec::new())
     }
 
-    fn binary(a: impl Into<CalcValue>, b: CalcProductPart) -> Self {
+    fn binary(a: impl Into<CalcValue<T>>, b: CalcProductPart) -> Self {
         Self(a.into(), vec![b])
     }
+
+    fn map<U>(&self, f: &impl Fn(&T) -> U) -> CalcProduct<U> {
+        CalcProduct(self.0.map(f), self.1.clone())
+    }
 }
-impl WriteValue for CalcProduct {
+impl<T: WriteValue> WriteValue for CalcProduct<T> {
     fn write_value(&self, f: &mut CssWriter) -> WriteResult {
         self.0.write_value(f)?;
         for part in &self.1 {
@@ -105,39 +240,347 @@ impl WriteValue for CalcProduct {
         Ok(())
     }
 }
-impl<T> From<T> for CalcProduct
+impl<T, V> From<V> for CalcProduct<T>
 where
-    T: Into<CalcValue>,
+    V: Into<CalcValue<T>>,
 {
-    fn from(v: T) -> Self {
+    fn from(v: V) -> Self {
         Self::unary(v)
     }
 }
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
-pub enum CalcValue {
-    Number(Number),
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum CalcValue<T> {
+    Value(T),
     #[value(prefix = "(", suffix = ")")]
-    CalcSum(Box<CalcSum>),
-    // dimensions
-    Angle(Angle),
-    Flex(Flex),
-    Frequency(Frequency),
-    Length(Length),
-    Percentage(Percentage),
-    Resolution(Resolution),
-    Time(Time),
-}
-impl<T> From<T> for CalcValue
-where
-    T: Into<NumberValueType>,
-{
+    CalcSum(Box<CalcSum<T>>),
+    Function(Box<MathFunction<T>>),
+}
+impl<T> CalcValue<T> {
+    fn map<U>(&self, f: &impl Fn(&T) -> U) -> CalcValue<U> {
+        match self {
+            Self::Value(v) => CalcValue::Value(f(v)),
+            Self::CalcSum(v) => CalcValue::CalcSum(Box::new(v.map(f))),
+            Self::Function(v) => CalcValue::Function(Box::new(v.map(f))),
+        }
+    }
+}
+impl<T: CalcType> From<T> for CalcValue<T> {
     fn from(v: T) -> Self {
-        Self::Number(Number::from(v))
+        Self::Value(v)
     }
 }
-impl From<Calc> for CalcValue {
-    fn from(v: Calc) -> Self {
+impl<T> From<Calc<T>> for CalcValue<T> {
+    fn from(v: Calc<T>) -> Self {
         Self::CalcSum(Box::new(v.0))
     }
 }
+impl<T> From<MathFunction<T>> for CalcValue<T> {
+    fn from(v: MathFunction<T>) -> Self {
+        Self::Function(Box::new(v))
+    }
+}
+impl From<NumberFunction> for CalcValue<Number> {
+    fn from(v: NumberFunction) -> Self {
+        Self::Value(Number::Function(Box::new(v)))
+    }
+}
+impl From<MathConstant> for CalcValue<Number> {
+    fn from(v: MathConstant) -> Self {
+        NumberFunction::Constant(v).into()
+    }
+}
+impl<V> From<V> for CalcValue<Number>
+where
+    V: Into<NumberValueType>,
+{
+    fn from(v: V) -> Self {
+        Self::Value(Number::from(v))
+    }
+}
+
+/// Allow dimensions and percentages in the calculations of the types which combine them.
+macro_rules! impl_calc_value_from {
+    ($($from:ty => $ty:ty,)*) => {
+        $(
+            impl From<$from> for CalcValue<$ty> {
+                fn from(v: $from) -> Self {
+                    Self::Value(v.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_calc_value_from! {
+    Angle => AnglePercentage,
+    Percentage => AnglePercentage,
+    Frequency => FrequencyPercentage,
+    Percentage => FrequencyPercentage,
+    Length => LengthPercentage,
+    Percentage => LengthPercentage,
+    Time => TimePercentage,
+    Percentage => TimePercentage,
+}
+
+/// Term of a simplified sum.
+enum Term<T> {
+    Dimension(CalcDimension),
+    /// Product which can't be folded, multiplied by `factor` and divided by `divisor`.
+    Product {
+        product: CalcProduct<T>,
+        factor: NumberValueType,
+        divisor: NumberValueType,
+    },
+}
+impl<T: CalcType> Term<T> {
+    fn scale(&mut self, factor: NumberValueType, divisor: NumberValueType) {
+        match self {
+            Self::Dimension((_, v)) => *v = *v * factor / divisor,
+            Self::Product {
+                factor: f,
+                divisor: d,
+                ..
+            } => {
+                *f *= factor;
+                *d *= divisor;
+            }
+        }
+    }
+
+    fn is_negative(&self) -> bool {
+        match self {
+            Self::Dimension((_, v)) => v.is_sign_negative(),
+            Self::Product { factor, .. } => factor.is_sign_negative(),
+        }
+    }
+
+    /// Turn the term into a product, using the absolute value if `abs` is set.
+    fn into_product(self, abs: bool) -> CalcProduct<T> {
+        let abs = |v: NumberValueType| if abs { v.abs() } else { v };
+        match self {
+            Self::Dimension((unit, v)) => CalcProduct::unary(
+                T::from_calc_dimension((unit, abs(v)))
+                    .expect("unit returned by CalcType::to_calc_dimension"),
+            ),
+            Self::Product {
+                mut product,
+                factor,
+                divisor,
+            } => {
+                let factor = abs(factor);
+                if factor != 1.0 {
+                    product.1.push(CalcProductPart::Mul(factor.into()));
+                }
+                if divisor != 1.0 {
+                    product.1.push(CalcProductPart::Div(divisor.into()));
+                }
+                product
+            }
+        }
+    }
+}
+
+struct Terms<T>(Vec<Term<T>>);
+impl<T: CalcType> Terms<T> {
+    fn opaque(product: CalcProduct<T>) -> Self {
+        Self(vec![Term::Product {
+            product,
+            factor: 1.0,
+            divisor: 1.0,
+        }])
+    }
+
+    /// Add the terms, combining dimensions which can be converted to the same unit.
+    fn extend(&mut self, other: Self) {
+        for term in other.0 {
+            if let Term::Dimension(dimension) = term {
+                if let Some(existing) = self.compatible_dimension(dimension) {
+                    *existing = combine_dimensions::<T>(*existing, dimension);
+                    continue;
+                }
+            }
+            self.0.push(term);
+        }
+    }
+
+    /// Dimension with the same unit, or one that can be converted to the same canonical unit.
+    fn compatible_dimension(&mut self, (unit, v): CalcDimension) -> Option<&mut CalcDimension> {
+        let canonical_unit = T::canonicalize((unit, v)).0;
+        let mut compatible = None;
+        for (i, term) in self.0.iter().enumerate() {
+            if let Term::Dimension(existing) = term {
+                if existing.0 == unit {
+                    compatible = Some(i);
+                    break;
+                }
+                if compatible.is_none() && T::canonicalize(*existing).0 == canonical_unit {
+                    compatible = Some(i);
+                }
+            }
+        }
+        match self.0.get_mut(compatible?) {
+            Some(Term::Dimension(dimension)) => Some(dimension),
+            _ => None,
+        }
+    }
+
+    fn scale(&mut self, factor: NumberValueType, divisor: NumberValueType) {
+        for term in &mut self.0 {
+            term.scale(factor, divisor);
+        }
+    }
+
+    fn dimension(&self) -> Option<CalcDimension> {
+        match self.0.as_slice() {
+            [Term::Dimension(dimension)] => Some(*dimension),
+            _ => None,
+        }
+    }
+
+    /// Order of the terms in a serialized sum: the number, the percentage, dimensions ordered by unit and finally
+    /// everything else in the order it was written.
+    fn sort(&mut self) {
+        self.0.sort_by_key(|term| match term {
+            Term::Dimension(("", _)) => (0, String::new()),
+            Term::Dimension(("%", _)) => (1, String::new()),
+            Term::Dimension((unit, _)) => (2, unit.to_ascii_lowercase()),
+            Term::Product { .. } => (3, String::new()),
+        });
+    }
+
+    fn into_sum(mut self) -> CalcSum<T> {
+        self.sort();
+        let mut terms = self.0.into_iter();
+        let first = terms
+            .next()
+            .expect("simplified sum has at least one term")
+            .into_product(false);
+        let parts = terms
+            .map(|term| {
+                if term.is_negative() {
+                    CalcSumPart::Sub(term.into_product(true))
+                } else {
+                    CalcSumPart::Add(term.into_product(false))
+                }
+            })
+            .collect();
+        CalcSum(first, parts)
+    }
+
+    fn into_value(self) -> CalcValue<T> {
+        let sum = self.into_sum();
+        match sum {
+            CalcSum(CalcProduct(value, factors), parts)
+                if factors.is_empty() && parts.is_empty() =>
+            {
+                value
+            }
+            sum => CalcValue::CalcSum(Box::new(sum)),
+        }
+    }
+}
+
+/// Add two dimensions, converting them to the canonical unit if they don't share the same unit.
+fn combine_dimensions<T: CalcType>(a: CalcDimension, b: CalcDimension) -> CalcDimension {
+    if a.0 == b.0 {
+        return (a.0, a.1 + b.1);
+    }
+    // adding zero doesn't need a conversion
+    if b.1 == 0.0 {
+        return a;
+    }
+    if a.1 == 0.0 {
+        return b;
+    }
+
+    let (unit, a) = T::canonicalize(a);
+    let (_, b) = T::canonicalize(b);
+    (unit, a + b)
+}
+
+/// Unit shared by all dimensions, converting them to the canonical unit if they use different ones.
+pub(crate) fn common_unit<T: CalcType>(dimensions: &mut [CalcDimension]) -> Option<&'static str> {
+    let unit = dimensions.first()?.0;
+    if dimensions.iter().all(|(u, _)| *u == unit) {
+        return Some(unit);
+    }
+
+    for dimension in dimensions.iter_mut() {
+        *dimension = T::canonicalize(*dimension);
+    }
+    let unit = dimensions[0].0;
+    if dimensions.iter().all(|(u, _)| *u == unit) {
+        Some(unit)
+    } else {
+        None
+    }
+}
+
+fn simplify_sum<T: CalcType>(sum: &CalcSum<T>) -> Terms<T> {
+    let mut terms = simplify_product(&sum.0);
+    for part in &sum.1 {
+        match part {
+            CalcSumPart::Add(v) => terms.extend(simplify_product(v)),
+            CalcSumPart::Sub(v) => {
+                let mut other = simplify_product(v);
+                other.scale(-1.0, 1.0);
+                terms.extend(other);
+            }
+        }
+    }
+    terms
+}
+
+fn simplify_product<T: CalcType>(product: &CalcProduct<T>) -> Terms<T> {
+    let mut factor = 1.0;
+    let mut divisor = 1.0;
+    let mut parts = Vec::with_capacity(product.1.len());
+    let mut constant = true;
+    for part in &product.1 {
+        let (v, is_div) = match part {
+            CalcProductPart::Mul(v) => (v, false),
+            CalcProductPart::Div(v) => (v, true),
+        };
+        let terms = simplify_value(v);
+        match terms.dimension().map(|(_, v)| v) {
+            Some(v) if is_div && v == 0.0 => constant = false,
+            Some(v) if is_div => divisor *= v,
+            Some(v) => factor *= v,
+            None => constant = false,
+        }
+        let v = terms.into_value();
+        parts.push(if is_div {
+            CalcProductPart::Div(v)
+        } else {
+            CalcProductPart::Mul(v)
+        });
+    }
+
+    let mut terms = simplify_value(&product.0);
+    if constant {
+        terms.scale(factor, divisor);
+        terms
+    } else {
+        Terms::opaque(CalcProduct(terms.into_value(), parts))
+    }
+}
+
+fn simplify_value<T: CalcType>(value: &CalcValue<T>) -> Terms<T> {
+    match value {
+        CalcValue::Value(v) => {
+            if let Some(dimension) = v.to_calc_dimension() {
+                Terms(vec![Term::Dimension(dimension)])
+            } else if let Some(calc) = v.to_calc() {
+                simplify_sum(&calc.0)
+            } else {
+                Terms::opaque(CalcProduct::unary(v.clone()))
+            }
+        }
+        CalcValue::CalcSum(v) => simplify_sum(v),
+        CalcValue::Function(v) => match v.resolve() {
+            Some(dimension) => Terms(vec![Term::Dimension(dimension)]),
+            None => Terms::opaque(CalcProduct::unary(v.simplify())),
+        },
+    }
+}
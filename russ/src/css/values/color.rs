@@ -0,0 +1,996 @@
+use super::{
+    Angle, ColorComponents, ColorSpace, NamedColor, Number, NumberPercentage, NumberValueType,
+    Percentage, SystemColor, Var,
+};
+use russ_internal::{CssValue, CssWriter, FromVariants, WriteResult, WriteValue};
+use std::io::Write;
+
+pub type HexValueType = u32;
+
+// https://developer.mozilla.org/en-US/docs/Web/CSS/color_value
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum Color {
+    Hex(HexColor),
+    #[function(separator = " ")]
+    Rgb {
+        r: NumberPercentage,
+        g: NumberPercentage,
+        b: NumberPercentage,
+        #[field(option, prefix = "/ ")]
+        a: Option<NumberPercentage>,
+    },
+    #[function(separator = " ")]
+    Hsl {
+        h: Angle,
+        s: Percentage,
+        l: Percentage,
+        #[field(option, prefix = "/ ")]
+        a: Option<NumberPercentage>,
+    },
+    #[function(separator = " ")]
+    Hwb {
+        h: Angle,
+        w: Percentage,
+        b: Percentage,
+        #[field(option, prefix = "/ ")]
+        alpha: Option<NumberPercentage>,
+    },
+    #[function(separator = " ")]
+    Lab {
+        l: NumberPercentage,
+        a: NumberPercentage,
+        b: NumberPercentage,
+        #[field(option, prefix = "/ ")]
+        alpha: Option<NumberPercentage>,
+    },
+    #[function(separator = " ")]
+    Lch {
+        l: NumberPercentage,
+        c: NumberPercentage,
+        h: Angle,
+        #[field(option, prefix = "/ ")]
+        alpha: Option<NumberPercentage>,
+    },
+    #[function(separator = " ")]
+    Oklab {
+        l: NumberPercentage,
+        a: NumberPercentage,
+        b: NumberPercentage,
+        #[field(option, prefix = "/ ")]
+        alpha: Option<NumberPercentage>,
+    },
+    #[function(separator = " ")]
+    Oklch {
+        l: NumberPercentage,
+        c: NumberPercentage,
+        h: Angle,
+        #[field(option, prefix = "/ ")]
+        alpha: Option<NumberPercentage>,
+    },
+    /// Color in one of the predefined color spaces using the `color()` function.
+    #[function(name = "color", separator = " ")]
+    Predefined {
+        space: PredefinedColorSpace,
+        c1: NumberPercentage,
+        c2: NumberPercentage,
+        c3: NumberPercentage,
+        #[field(option, prefix = "/ ")]
+        alpha: Option<NumberPercentage>,
+    },
+
+    Named(NamedColor),
+    System(SystemColor),
+    #[keyword]
+    Transparent,
+    #[keyword(value = "currentcolor")]
+    CurrentColor,
+
+    Var(Var<Color>),
+    Mix(Box<ColorMix>),
+    Relative(Box<RelativeColor>),
+}
+impl Color {
+    /// Opaque hex color in the form `0xRRGGBB`.
+    /// Use [`HexColor`] for colors with an alpha channel.
+    pub const fn hex(hex: HexValueType) -> Self {
+        Self::Hex(HexColor::rgb(hex))
+    }
+
+    pub fn rgb(
+        r: impl Into<NumberPercentage>,
+        g: impl Into<NumberPercentage>,
+        b: impl Into<NumberPercentage>,
+    ) -> Self {
+        Self::Rgb {
+            r: r.into(),
+            g: g.into(),
+            b: b.into(),
+            a: None,
+        }
+    }
+
+    pub fn rgba(
+        r: impl Into<NumberPercentage>,
+        g: impl Into<NumberPercentage>,
+        b: impl Into<NumberPercentage>,
+        a: impl Into<NumberPercentage>,
+    ) -> Self {
+        Self::Rgb {
+            r: r.into(),
+            g: g.into(),
+            b: b.into(),
+            a: Some(a.into()),
+        }
+    }
+
+    pub fn hsl(h: impl Into<Angle>, s: impl Into<Percentage>, l: impl Into<Percentage>) -> Self {
+        Self::Hsl {
+            h: h.into(),
+            s: s.into(),
+            l: l.into(),
+            a: None,
+        }
+    }
+
+    pub fn hsla(
+        h: impl Into<Angle>,
+        s: impl Into<Percentage>,
+        l: impl Into<Percentage>,
+        a: impl Into<NumberPercentage>,
+    ) -> Self {
+        Self::Hsl {
+            h: h.into(),
+            s: s.into(),
+            l: l.into(),
+            a: Some(a.into()),
+        }
+    }
+
+    pub fn hwb(h: impl Into<Angle>, w: impl Into<Percentage>, b: impl Into<Percentage>) -> Self {
+        Self::Hwb {
+            h: h.into(),
+            w: w.into(),
+            b: b.into(),
+            alpha: None,
+        }
+    }
+
+    pub fn lab(
+        l: impl Into<NumberPercentage>,
+        a: impl Into<NumberPercentage>,
+        b: impl Into<NumberPercentage>,
+    ) -> Self {
+        Self::Lab {
+            l: l.into(),
+            a: a.into(),
+            b: b.into(),
+            alpha: None,
+        }
+    }
+
+    pub fn lch(
+        l: impl Into<NumberPercentage>,
+        c: impl Into<NumberPercentage>,
+        h: impl Into<Angle>,
+    ) -> Self {
+        Self::Lch {
+            l: l.into(),
+            c: c.into(),
+            h: h.into(),
+            alpha: None,
+        }
+    }
+
+    pub fn oklab(
+        l: impl Into<NumberPercentage>,
+        a: impl Into<NumberPercentage>,
+        b: impl Into<NumberPercentage>,
+    ) -> Self {
+        Self::Oklab {
+            l: l.into(),
+            a: a.into(),
+            b: b.into(),
+            alpha: None,
+        }
+    }
+
+    pub fn oklch(
+        l: impl Into<NumberPercentage>,
+        c: impl Into<NumberPercentage>,
+        h: impl Into<Angle>,
+    ) -> Self {
+        Self::Oklch {
+            l: l.into(),
+            c: c.into(),
+            h: h.into(),
+            alpha: None,
+        }
+    }
+
+    pub fn predefined(
+        space: PredefinedColorSpace,
+        c1: impl Into<NumberPercentage>,
+        c2: impl Into<NumberPercentage>,
+        c3: impl Into<NumberPercentage>,
+    ) -> Self {
+        Self::Predefined {
+            space,
+            c1: c1.into(),
+            c2: c2.into(),
+            c3: c3.into(),
+            alpha: None,
+        }
+    }
+
+    pub fn display_p3(
+        r: impl Into<NumberPercentage>,
+        g: impl Into<NumberPercentage>,
+        b: impl Into<NumberPercentage>,
+    ) -> Self {
+        Self::predefined(PredefinedColorSpace::DisplayP3, r, g, b)
+    }
+
+    /// Numeric representation of the color.
+    ///
+    /// Returns `None` if the color depends on the context (`currentcolor`, system colors, `var()`),
+    /// contains a `calc()`, is a relative color or uses a color space which isn't supported.
+    pub fn resolve(&self) -> Option<ColorComponents> {
+        let components = match self {
+            Self::Hex(hex) => ColorComponents::from_hex(hex.to_rgb())
+                .with_alpha(NumberValueType::from(hex.a) / 255.0),
+            Self::Named(named) => ColorComponents::from_hex(named.to_hex()),
+            Self::Transparent => ColorComponents::srgb(0.0, 0.0, 0.0).with_alpha(0.0),
+            Self::Rgb { r, g, b, a } => ColorComponents::srgb(
+                resolve_channel(r, 255.0)? / 255.0,
+                resolve_channel(g, 255.0)? / 255.0,
+                resolve_channel(b, 255.0)? / 255.0,
+            )
+            .with_alpha(resolve_alpha(a)?),
+            Self::Hsl { h, s, l, a } => ColorComponents::new(
+                ColorSpace::Hsl,
+                [
+                    h.to_degrees()?,
+                    resolve_percentage(s)?,
+                    resolve_percentage(l)?,
+                ],
+            )
+            .with_alpha(resolve_alpha(a)?),
+            Self::Hwb { h, w, b, alpha } => ColorComponents::new(
+                ColorSpace::Hwb,
+                [
+                    h.to_degrees()?,
+                    resolve_percentage(w)?,
+                    resolve_percentage(b)?,
+                ],
+            )
+            .with_alpha(resolve_alpha(alpha)?),
+            Self::Lab { l, a, b, alpha } => ColorComponents::new(
+                ColorSpace::Lab,
+                [
+                    resolve_channel(l, 100.0)?,
+                    resolve_channel(a, 125.0)?,
+                    resolve_channel(b, 125.0)?,
+                ],
+            )
+            .with_alpha(resolve_alpha(alpha)?),
+            Self::Lch { l, c, h, alpha } => ColorComponents::new(
+                ColorSpace::Lch,
+                [
+                    resolve_channel(l, 100.0)?,
+                    resolve_channel(c, 150.0)?,
+                    h.to_degrees()?,
+                ],
+            )
+            .with_alpha(resolve_alpha(alpha)?),
+            Self::Oklab { l, a, b, alpha } => ColorComponents::new(
+                ColorSpace::Oklab,
+                [
+                    resolve_channel(l, 1.0)?,
+                    resolve_channel(a, 0.4)?,
+                    resolve_channel(b, 0.4)?,
+                ],
+            )
+            .with_alpha(resolve_alpha(alpha)?),
+            Self::Oklch { l, c, h, alpha } => ColorComponents::new(
+                ColorSpace::Oklch,
+                [
+                    resolve_channel(l, 1.0)?,
+                    resolve_channel(c, 0.4)?,
+                    h.to_degrees()?,
+                ],
+            )
+            .with_alpha(resolve_alpha(alpha)?),
+            Self::Predefined {
+                space,
+                c1,
+                c2,
+                c3,
+                alpha,
+            } => {
+                let space = match space {
+                    PredefinedColorSpace::Srgb => ColorSpace::Srgb,
+                    PredefinedColorSpace::SrgbLinear => ColorSpace::SrgbLinear,
+                    PredefinedColorSpace::Xyz | PredefinedColorSpace::XyzD65 => ColorSpace::XyzD65,
+                    PredefinedColorSpace::XyzD50 => ColorSpace::XyzD50,
+                    _ => return None,
+                };
+                ColorComponents::new(
+                    space,
+                    [
+                        resolve_channel(c1, 1.0)?,
+                        resolve_channel(c2, 1.0)?,
+                        resolve_channel(c3, 1.0)?,
+                    ],
+                )
+                .with_alpha(resolve_alpha(alpha)?)
+            }
+            Self::Mix(mix) => return mix.resolve(),
+            Self::System(_) | Self::CurrentColor | Self::Var(_) | Self::Relative(_) => return None,
+        };
+        Some(components)
+    }
+
+    /// Re-encode the color in the given color space.
+    pub fn to_space(&self, space: ColorSpace) -> Option<Self> {
+        self.resolve().map(|c| c.to_space(space).into())
+    }
+
+    /// sRGB value of the color as `0xRRGGBB`.
+    /// Colors outside of the sRGB gamut are clamped and the alpha channel is discarded.
+    pub fn to_hex(&self) -> Option<HexValueType> {
+        self.resolve().map(ColorComponents::to_hex)
+    }
+
+    /// See [`ColorComponents::relative_luminance`].
+    pub fn relative_luminance(&self) -> Option<NumberValueType> {
+        self.resolve().map(ColorComponents::relative_luminance)
+    }
+
+    pub fn with_alpha(&self, alpha: NumberValueType) -> Option<Self> {
+        self.map_components(|c| c.with_alpha(alpha))
+    }
+
+    /// See [`ColorComponents::lighten`].
+    pub fn lighten(&self, amount: NumberValueType) -> Option<Self> {
+        self.map_components(|c| c.lighten(amount))
+    }
+
+    /// See [`ColorComponents::darken`].
+    pub fn darken(&self, amount: NumberValueType) -> Option<Self> {
+        self.map_components(|c| c.darken(amount))
+    }
+
+    /// See [`ColorComponents::saturate`].
+    pub fn saturate(&self, amount: NumberValueType) -> Option<Self> {
+        self.map_components(|c| c.saturate(amount))
+    }
+
+    /// See [`ColorComponents::desaturate`].
+    pub fn desaturate(&self, amount: NumberValueType) -> Option<Self> {
+        self.map_components(|c| c.desaturate(amount))
+    }
+
+    /// See [`ColorComponents::mix`].
+    pub fn mix(
+        &self,
+        other: &Self,
+        ratio: NumberValueType,
+        method: impl Into<ColorInterpolationMethod>,
+    ) -> Option<Self> {
+        let mixed = self
+            .resolve()?
+            .mix(other.resolve()?, ratio, method.into())?;
+        Some(mixed.into())
+    }
+
+    /// Apply `f` to the numeric representation of the color.
+    /// Hex and named colors stay hex colors.
+    fn map_components(&self, f: impl FnOnce(ColorComponents) -> ColorComponents) -> Option<Self> {
+        let components = f(self.resolve()?);
+        match self {
+            Self::Hex(hex) => Some(Self::Hex(HexColor {
+                short: hex.short,
+                ..HexColor::from_components(components)
+            })),
+            Self::Named(_) => Some(Self::Hex(HexColor::from_components(components))),
+            _ => Some(components.into()),
+        }
+    }
+}
+impl From<HexColor> for Color {
+    fn from(v: HexColor) -> Self {
+        Self::Hex(v)
+    }
+}
+impl From<NamedColor> for Color {
+    fn from(v: NamedColor) -> Self {
+        Self::Named(v)
+    }
+}
+impl From<SystemColor> for Color {
+    fn from(v: SystemColor) -> Self {
+        Self::System(v)
+    }
+}
+impl From<ColorComponents> for Color {
+    fn from(v: ColorComponents) -> Self {
+        let [c0, c1, c2] = v.channels;
+        let alpha = if v.alpha >= 1.0 {
+            None
+        } else {
+            Some(encode(v.alpha).into())
+        };
+        let percentage = |v: NumberValueType| Percentage(encode(v * 100.0));
+        let predefined = |space| Self::Predefined {
+            space,
+            c1: encode(c0).into(),
+            c2: encode(c1).into(),
+            c3: encode(c2).into(),
+            alpha: alpha.clone(),
+        };
+        match v.space {
+            ColorSpace::Srgb => Self::Rgb {
+                r: encode(c0 * 255.0).into(),
+                g: encode(c1 * 255.0).into(),
+                b: encode(c2 * 255.0).into(),
+                a: alpha,
+            },
+            ColorSpace::SrgbLinear => predefined(PredefinedColorSpace::SrgbLinear),
+            ColorSpace::XyzD50 => predefined(PredefinedColorSpace::XyzD50),
+            ColorSpace::XyzD65 => predefined(PredefinedColorSpace::XyzD65),
+            ColorSpace::Hsl => Self::Hsl {
+                h: Angle::deg(encode(c0)),
+                s: percentage(c1),
+                l: percentage(c2),
+                a: alpha,
+            },
+            ColorSpace::Hwb => Self::Hwb {
+                h: Angle::deg(encode(c0)),
+                w: percentage(c1),
+                b: percentage(c2),
+                alpha,
+            },
+            ColorSpace::Lab => Self::Lab {
+                l: encode(c0).into(),
+                a: encode(c1).into(),
+                b: encode(c2).into(),
+                alpha,
+            },
+            ColorSpace::Lch => Self::Lch {
+                l: encode(c0).into(),
+                c: encode(c1).into(),
+                h: Angle::deg(encode(c2)),
+                alpha,
+            },
+            ColorSpace::Oklab => Self::Oklab {
+                l: encode(c0).into(),
+                a: encode(c1).into(),
+                b: encode(c2).into(),
+                alpha,
+            },
+            ColorSpace::Oklch => Self::Oklch {
+                l: encode(c0).into(),
+                c: encode(c1).into(),
+                h: Angle::deg(encode(c2)),
+                alpha,
+            },
+        }
+    }
+}
+impl From<Var<Color>> for Color {
+    fn from(v: Var<Color>) -> Self {
+        Self::Var(v)
+    }
+}
+impl From<ColorMix> for Color {
+    fn from(v: ColorMix) -> Self {
+        Self::Mix(Box::new(v))
+    }
+}
+impl From<RelativeColor> for Color {
+    fn from(v: RelativeColor) -> Self {
+        Self::Relative(Box::new(v))
+    }
+}
+
+// https://drafts.csswg.org/css-color-4/#hex-notation
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct HexColor {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    /// The alpha channel is omitted when the color is fully opaque.
+    pub a: u8,
+    /// Use the 3 or 4 digit form if the color can be expressed in it.
+    pub short: bool,
+}
+impl HexColor {
+    /// Opaque color in the form `0xRRGGBB`.
+    pub const fn rgb(hex: HexValueType) -> Self {
+        Self {
+            r: (hex >> 16) as u8,
+            g: (hex >> 8) as u8,
+            b: hex as u8,
+            a: u8::MAX,
+            short: false,
+        }
+    }
+
+    /// Color in the form `0xRRGGBBAA`.
+    pub const fn rgba(hex: HexValueType) -> Self {
+        Self::rgb(hex >> 8).with_alpha(hex as u8)
+    }
+
+    /// Parse a color in the form `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA`.
+    /// The `#` is optional and the digits are case-insensitive.
+    ///
+    /// Colors given in the 3 or 4 digit form keep using it.
+    pub fn parse(s: &str) -> Option<Self> {
+        let digits = s.strip_prefix('#').unwrap_or(s);
+        if !digits.bytes().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let (channels, short) = match digits.len() {
+            3 | 4 => {
+                let channels = digits
+                    .chars()
+                    .map(|c| c.to_digit(16).map(|v| v as u8 * 0x11))
+                    .collect::<Option<Vec<_>>>()?;
+                (channels, true)
+            }
+            6 | 8 => {
+                let channels = (0..digits.len())
+                    .step_by(2)
+                    .map(|i| u8::from_str_radix(&digits[i..i + 2], 16).ok())
+                    .collect::<Option<Vec<_>>>()?;
+                (channels, false)
+            }
+            _ => return None,
+        };
+        Some(Self {
+            r: channels[0],
+            g: channels[1],
+            b: channels[2],
+            a: channels.get(3).copied().unwrap_or(u8::MAX),
+            short,
+        })
+    }
+
+    /// Clamp the color to the sRGB gamut.
+    pub fn from_components(components: ColorComponents) -> Self {
+        let alpha = (components.alpha * 255.0).round() as u8;
+        Self::rgb(components.to_hex()).with_alpha(alpha)
+    }
+
+    pub const fn with_alpha(self, a: u8) -> Self {
+        Self { a, ..self }
+    }
+
+    /// Write the color in the shortest form possible.
+    pub const fn shortest(self) -> Self {
+        Self {
+            short: true,
+            ..self
+        }
+    }
+
+    /// Color without the alpha channel in the form `0xRRGGBB`.
+    pub const fn to_rgb(self) -> HexValueType {
+        (self.r as HexValueType) << 16 | (self.g as HexValueType) << 8 | self.b as HexValueType
+    }
+
+    pub const fn is_opaque(self) -> bool {
+        self.a == u8::MAX
+    }
+
+    fn can_shorten(self) -> bool {
+        [self.r, self.g, self.b, self.a]
+            .iter()
+            .all(|c| c >> 4 == c & 0xF)
+    }
+}
+impl WriteValue for HexColor {
+    fn write_value(&self, f: &mut CssWriter) -> WriteResult {
+        f.write_char('#')?;
+        let channels = [self.r, self.g, self.b, self.a];
+        let channels = if self.is_opaque() {
+            &channels[..3]
+        } else {
+            &channels[..]
+        };
+
+        if self.short && self.can_shorten() {
+            for c in channels {
+                write!(f, "{:X}", c & 0xF)?;
+            }
+        } else {
+            for c in channels {
+                write!(f, "{:02X}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// https://drafts.csswg.org/css-color-5/#color-mix
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+#[function(name = "color-mix")]
+pub struct ColorMix {
+    pub method: ColorInterpolationMethod,
+    pub first: ColorMixComponent,
+    pub second: ColorMixComponent,
+}
+impl ColorMix {
+    pub fn build(
+        method: impl Into<ColorInterpolationMethod>,
+        first: impl Into<ColorMixComponent>,
+        second: impl Into<ColorMixComponent>,
+    ) -> Self {
+        Self {
+            method: method.into(),
+            first: first.into(),
+            second: second.into(),
+        }
+    }
+
+    /// Compute the mixed color if both colors can be resolved.
+    pub fn resolve(&self) -> Option<ColorComponents> {
+        let first = self.first.color.resolve()?;
+        let second = self.second.color.resolve()?;
+        let p1 = self.first.percentage.as_ref().map(resolve_percentage);
+        let p2 = self.second.percentage.as_ref().map(resolve_percentage);
+        let (p1, p2) = match (p1, p2) {
+            (None, None) => (0.5, 0.5),
+            (Some(p1), None) => (p1?, 1.0 - p1?),
+            (None, Some(p2)) => (1.0 - p2?, p2?),
+            (Some(p1), Some(p2)) => (p1?, p2?),
+        };
+        let sum = p1 + p2;
+        if sum <= 0.0 {
+            return None;
+        }
+
+        let mixed = first.mix(second, p2 / sum, self.method)?;
+        // percentages that add up to less than 100% make the result transparent
+        Some(mixed.with_alpha(mixed.alpha * sum.min(1.0)))
+    }
+}
+
+/// Color with an optional percentage in a `color-mix()` function.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+#[value]
+pub struct ColorMixComponent {
+    pub color: Color,
+    pub percentage: Option<Percentage>,
+}
+impl ColorMixComponent {
+    pub fn new(color: impl Into<Color>, percentage: impl Into<Percentage>) -> Self {
+        Self {
+            color: color.into(),
+            percentage: Some(percentage.into()),
+        }
+    }
+}
+impl From<Color> for ColorMixComponent {
+    fn from(color: Color) -> Self {
+        Self {
+            color,
+            percentage: None,
+        }
+    }
+}
+
+/// Color function a relative color is expressed in.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum RelativeColorFunction {
+    Rgb,
+    Hsl,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+    Predefined(PredefinedColorSpace),
+}
+impl RelativeColorFunction {
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Rgb => "rgb",
+            Self::Hsl => "hsl",
+            Self::Hwb => "hwb",
+            Self::Lab => "lab",
+            Self::Lch => "lch",
+            Self::Oklab => "oklab",
+            Self::Oklch => "oklch",
+            Self::Predefined(_) => "color",
+        }
+    }
+
+    /// Keywords which refer to the channels of the origin color.
+    pub fn channel_keywords(self) -> [ChannelKeyword; 3] {
+        use ChannelKeyword::*;
+        match self {
+            Self::Rgb => [R, G, B],
+            Self::Hsl => [H, S, L],
+            Self::Hwb => [H, W, B],
+            Self::Lab | Self::Oklab => [L, A, B],
+            Self::Lch | Self::Oklch => [L, C, H],
+            Self::Predefined(PredefinedColorSpace::Xyz)
+            | Self::Predefined(PredefinedColorSpace::XyzD50)
+            | Self::Predefined(PredefinedColorSpace::XyzD65) => [X, Y, Z],
+            Self::Predefined(_) => [R, G, B],
+        }
+    }
+
+    /// Position of the hue channel, the only channel which accepts an angle.
+    pub fn hue_channel(self) -> Option<usize> {
+        match self {
+            Self::Hsl | Self::Hwb => Some(0),
+            Self::Lch | Self::Oklch => Some(2),
+            _ => None,
+        }
+    }
+}
+
+// https://drafts.csswg.org/css-color-5/#relative-colors
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum ChannelKeyword {
+    #[keyword]
+    R,
+    #[keyword]
+    G,
+    #[keyword]
+    B,
+    #[keyword]
+    H,
+    #[keyword]
+    S,
+    #[keyword]
+    L,
+    #[keyword]
+    W,
+    #[keyword]
+    A,
+    #[keyword]
+    C,
+    #[keyword]
+    X,
+    #[keyword]
+    Y,
+    #[keyword]
+    Z,
+    #[keyword]
+    Alpha,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum RelativeColorChannel {
+    Keyword(ChannelKeyword),
+    #[from_variant(into)]
+    Value(NumberPercentage),
+    /// Only valid for the hue channel.
+    Angle(Angle),
+}
+
+/// Color derived from the channels of an origin color, such as `rgb(from var(--brand) r g b / 50%)`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RelativeColor {
+    pub function: RelativeColorFunction,
+    pub origin: Color,
+    pub channels: [RelativeColorChannel; 3],
+    pub alpha: Option<RelativeColorChannel>,
+}
+impl RelativeColor {
+    /// Relative color which keeps all channels of the origin color.
+    pub fn build(function: RelativeColorFunction, origin: impl Into<Color>) -> Self {
+        let [c1, c2, c3] = function.channel_keywords();
+        Self {
+            function,
+            origin: origin.into(),
+            channels: [c1.into(), c2.into(), c3.into()],
+            alpha: None,
+        }
+    }
+
+    /// Returns `None` if a keyword doesn't belong to the function or an angle is used for a channel other than the
+    /// hue.
+    pub fn with_channels(
+        mut self,
+        c1: impl Into<RelativeColorChannel>,
+        c2: impl Into<RelativeColorChannel>,
+        c3: impl Into<RelativeColorChannel>,
+    ) -> Option<Self> {
+        let channels = [c1.into(), c2.into(), c3.into()];
+        let hue_channel = self.function.hue_channel();
+        for (i, channel) in channels.iter().enumerate() {
+            if !self.accepts(channel, hue_channel == Some(i)) {
+                return None;
+            }
+        }
+
+        self.channels = channels;
+        Some(self)
+    }
+
+    /// Returns `None` if the alpha is an angle or a keyword which doesn't belong to the function.
+    pub fn with_alpha(mut self, alpha: impl Into<RelativeColorChannel>) -> Option<Self> {
+        let alpha = alpha.into();
+        if !self.accepts(&alpha, false) {
+            return None;
+        }
+
+        self.alpha = Some(alpha);
+        Some(self)
+    }
+
+    fn accepts(&self, channel: &RelativeColorChannel, is_hue: bool) -> bool {
+        match channel {
+            RelativeColorChannel::Keyword(ChannelKeyword::Alpha) => true,
+            RelativeColorChannel::Keyword(keyword) => {
+                self.function.channel_keywords().contains(keyword)
+            }
+            RelativeColorChannel::Value(_) => true,
+            RelativeColorChannel::Angle(_) => is_hue,
+        }
+    }
+}
+impl WriteValue for RelativeColor {
+    fn write_value(&self, f: &mut CssWriter) -> WriteResult {
+        f.write_str(self.function.name())?;
+        f.write_str("(from ")?;
+        self.origin.write_value(f)?;
+        if let RelativeColorFunction::Predefined(space) = self.function {
+            f.write_char(' ')?;
+            space.write_value(f)?;
+        }
+        for channel in &self.channels {
+            f.write_char(' ')?;
+            channel.write_value(f)?;
+        }
+        if let Some(alpha) = &self.alpha {
+            f.write_str(" / ")?;
+            alpha.write_value(f)?;
+        }
+        f.write_char(')')
+    }
+}
+
+/// Contrast ratio between two colors as defined by WCAG in the range `1..=21`.
+///
+/// A translucent `foreground` is composited over the `background`.
+/// The alpha channel of the `background` is ignored.
+pub fn contrast_ratio(foreground: &Color, background: &Color) -> Option<NumberValueType> {
+    let background = background.resolve()?.to_space(ColorSpace::Srgb);
+    let foreground = foreground.resolve()?.to_space(ColorSpace::Srgb);
+    let mut composited = [0.0; 3];
+    for (i, c) in composited.iter_mut().enumerate() {
+        *c = foreground.channels[i] * foreground.alpha
+            + background.channels[i] * (1.0 - foreground.alpha);
+    }
+
+    let l1 = ColorComponents::new(ColorSpace::Srgb, composited).relative_luminance();
+    let l2 = background.relative_luminance();
+    Some((l1.max(l2) + 0.05) / (l1.min(l2) + 0.05))
+}
+
+fn resolve_channel(
+    v: &NumberPercentage,
+    percentage_basis: NumberValueType,
+) -> Option<NumberValueType> {
+    match v {
+        NumberPercentage::Number(v) => v.value(),
+        NumberPercentage::Percentage(v) => resolve_percentage(v).map(|v| v * percentage_basis),
+    }
+}
+
+fn resolve_percentage(v: &Percentage) -> Option<NumberValueType> {
+    v.0.value().map(|v| v / 100.0)
+}
+
+fn resolve_alpha(alpha: &Option<NumberPercentage>) -> Option<NumberValueType> {
+    alpha
+        .as_ref()
+        .map_or(Some(1.0), |alpha| resolve_channel(alpha, 1.0))
+}
+
+/// Round away floating point noise so converted colors serialize nicely.
+fn encode(v: NumberValueType) -> Number {
+    // adding zero turns -0 into 0
+    Number::from((v * 1e5).round() / 1e5 + 0.0)
+}
+
+// https://drafts.csswg.org/css-color-4/#predefined
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum PredefinedColorSpace {
+    #[keyword]
+    Srgb,
+    #[keyword]
+    SrgbLinear,
+    #[keyword(value = "display-p3")]
+    DisplayP3,
+    #[keyword(value = "a98-rgb")]
+    A98Rgb,
+    #[keyword]
+    ProphotoRgb,
+    #[keyword(value = "rec2020")]
+    Rec2020,
+    #[keyword]
+    Xyz,
+    #[keyword(value = "xyz-d50")]
+    XyzD50,
+    #[keyword(value = "xyz-d65")]
+    XyzD65,
+}
+
+// https://drafts.csswg.org/css-color-4/#typedef-rectangular-color-space
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum RectangularColorSpace {
+    #[keyword]
+    Srgb,
+    #[keyword]
+    SrgbLinear,
+    #[keyword(value = "display-p3")]
+    DisplayP3,
+    #[keyword(value = "a98-rgb")]
+    A98Rgb,
+    #[keyword]
+    ProphotoRgb,
+    #[keyword(value = "rec2020")]
+    Rec2020,
+    #[keyword]
+    Lab,
+    #[keyword]
+    Oklab,
+    #[keyword]
+    Xyz,
+    #[keyword(value = "xyz-d50")]
+    XyzD50,
+    #[keyword(value = "xyz-d65")]
+    XyzD65,
+}
+
+// https://drafts.csswg.org/css-color-4/#typedef-polar-color-space
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum PolarColorSpace {
+    #[keyword]
+    Hsl,
+    #[keyword]
+    Hwb,
+    #[keyword]
+    Lch,
+    #[keyword]
+    Oklch,
+}
+
+// https://drafts.csswg.org/css-color-4/#typedef-hue-interpolation-method
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum HueInterpolationMethod {
+    #[keyword(value = "shorter hue")]
+    Shorter,
+    #[keyword(value = "longer hue")]
+    Longer,
+    #[keyword(value = "increasing hue")]
+    Increasing,
+    #[keyword(value = "decreasing hue")]
+    Decreasing,
+}
+
+// https://drafts.csswg.org/css-color-4/#color-interpolation-method
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum ColorInterpolationMethod {
+    #[value(prefix = "in ")]
+    Rectangular(RectangularColorSpace),
+    #[value(prefix = "in ")]
+    Polar(PolarColorSpace, Option<HueInterpolationMethod>),
+}
+impl ColorInterpolationMethod {
+    pub fn polar(space: PolarColorSpace, hue: HueInterpolationMethod) -> Self {
+        Self::Polar(space, Some(hue))
+    }
+}
+impl From<PolarColorSpace> for ColorInterpolationMethod {
+    fn from(space: PolarColorSpace) -> Self {
+        Self::Polar(space, None)
+    }
+}
@@ -0,0 +1,546 @@
+use super::{
+    ColorInterpolationMethod, HexValueType, HueInterpolationMethod, NumberValueType,
+    PolarColorSpace, RectangularColorSpace,
+};
+
+/// Color spaces which colors can be converted between.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ColorSpace {
+    /// Red, green and blue in the range `0..=1`.
+    Srgb,
+    /// Linear-light red, green and blue in the range `0..=1`.
+    SrgbLinear,
+    /// Hue in degrees, saturation and lightness in the range `0..=1`.
+    Hsl,
+    /// Hue in degrees, whiteness and blackness in the range `0..=1`.
+    Hwb,
+    /// CIE lightness in the range `0..=100`, a and b axes.
+    Lab,
+    /// CIE lightness in the range `0..=100`, chroma and hue in degrees.
+    Lch,
+    /// OK lightness in the range `0..=1`, a and b axes.
+    Oklab,
+    /// OK lightness in the range `0..=1`, chroma and hue in degrees.
+    Oklch,
+    /// CIE XYZ relative to the D50 white point.
+    XyzD50,
+    /// CIE XYZ relative to the D65 white point.
+    XyzD65,
+}
+impl ColorSpace {
+    /// Color space used to interpolate in the given rectangular space.
+    /// Returns `None` for wide gamut RGB spaces which aren't supported.
+    pub fn from_rectangular(space: RectangularColorSpace) -> Option<Self> {
+        match space {
+            RectangularColorSpace::Srgb => Some(Self::Srgb),
+            RectangularColorSpace::SrgbLinear => Some(Self::SrgbLinear),
+            RectangularColorSpace::Lab => Some(Self::Lab),
+            RectangularColorSpace::Oklab => Some(Self::Oklab),
+            RectangularColorSpace::Xyz | RectangularColorSpace::XyzD65 => Some(Self::XyzD65),
+            RectangularColorSpace::XyzD50 => Some(Self::XyzD50),
+            RectangularColorSpace::DisplayP3
+            | RectangularColorSpace::A98Rgb
+            | RectangularColorSpace::ProphotoRgb
+            | RectangularColorSpace::Rec2020 => None,
+        }
+    }
+
+    /// Index of the channel holding the hue for polar color spaces.
+    pub fn hue_channel(self) -> Option<usize> {
+        match self {
+            Self::Hsl | Self::Hwb => Some(0),
+            Self::Lch | Self::Oklch => Some(2),
+            _ => None,
+        }
+    }
+
+    fn is_srgb_based(self) -> bool {
+        matches!(self, Self::Srgb | Self::Hsl | Self::Hwb)
+    }
+}
+impl From<PolarColorSpace> for ColorSpace {
+    fn from(space: PolarColorSpace) -> Self {
+        match space {
+            PolarColorSpace::Hsl => Self::Hsl,
+            PolarColorSpace::Hwb => Self::Hwb,
+            PolarColorSpace::Lch => Self::Lch,
+            PolarColorSpace::Oklch => Self::Oklch,
+        }
+    }
+}
+
+/// Numeric representation of a color in a specific color space.
+///
+/// Channels aren't clamped so colors which are out of gamut survive conversions between spaces.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorComponents {
+    pub space: ColorSpace,
+    pub channels: [NumberValueType; 3],
+    pub alpha: NumberValueType,
+}
+impl ColorComponents {
+    pub const fn new(space: ColorSpace, channels: [NumberValueType; 3]) -> Self {
+        Self {
+            space,
+            channels,
+            alpha: 1.0,
+        }
+    }
+
+    pub const fn srgb(r: NumberValueType, g: NumberValueType, b: NumberValueType) -> Self {
+        Self::new(ColorSpace::Srgb, [r, g, b])
+    }
+
+    /// sRGB color from a hex value in the form `0xRRGGBB`.
+    pub fn from_hex(hex: HexValueType) -> Self {
+        let channel = |shift: u32| NumberValueType::from((hex >> shift) as u8) / 255.0;
+        Self::srgb(channel(16), channel(8), channel(0))
+    }
+
+    /// Clamp the color to the sRGB gamut and encode it in the form `0xRRGGBB`.
+    /// The alpha channel is discarded.
+    pub fn to_hex(self) -> HexValueType {
+        let [r, g, b] = self.to_space(ColorSpace::Srgb).channels;
+        let channel = |v: NumberValueType| (v.clamp(0.0, 1.0) * 255.0).round() as HexValueType;
+        channel(r) << 16 | channel(g) << 8 | channel(b)
+    }
+
+    pub fn with_alpha(self, alpha: NumberValueType) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+            ..self
+        }
+    }
+
+    /// Convert the color to another color space.
+    pub fn to_space(self, space: ColorSpace) -> Self {
+        if self.space == space {
+            return self;
+        }
+
+        let channels = if self.space.is_srgb_based() && space.is_srgb_based() {
+            from_srgb(space, to_srgb(self.space, self.channels))
+        } else {
+            from_xyz_d65(space, to_xyz_d65(self.space, self.channels))
+        };
+        Self {
+            space,
+            channels,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Relative luminance as defined by WCAG, after clamping the color to the sRGB gamut.
+    /// The alpha channel is ignored.
+    pub fn relative_luminance(self) -> NumberValueType {
+        let [r, g, b] = self.to_space(ColorSpace::Srgb).channels;
+        let channel = |v: NumberValueType| srgb_to_linear(v.clamp(0.0, 1.0));
+        0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+    }
+
+    /// Whether the hue of the color carries no information.
+    pub fn is_achromatic(self) -> bool {
+        let [_, c1, c2] = self.channels;
+        match self.space {
+            ColorSpace::Hsl => c1.abs() < 1e-6,
+            ColorSpace::Hwb => c1 + c2 >= 1.0 - 1e-6,
+            ColorSpace::Lch => c1.abs() < ACHROMATIC_LCH_CHROMA,
+            ColorSpace::Oklch => c1.abs() < ACHROMATIC_OKLCH_CHROMA,
+            _ => self.to_space(ColorSpace::Oklch).is_achromatic(),
+        }
+    }
+
+    fn map_hsl(self, f: impl FnOnce(&mut [NumberValueType; 3])) -> Self {
+        let mut hsl = self.to_space(ColorSpace::Hsl);
+        f(&mut hsl.channels);
+        hsl.to_space(self.space)
+    }
+
+    /// Increase the HSL lightness by the given amount in the range `0..=1`.
+    pub fn lighten(self, amount: NumberValueType) -> Self {
+        self.map_hsl(|[_, _, l]| *l = (*l + amount).clamp(0.0, 1.0))
+    }
+
+    /// Decrease the HSL lightness by the given amount in the range `0..=1`.
+    pub fn darken(self, amount: NumberValueType) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Increase the HSL saturation by the given amount in the range `0..=1`.
+    pub fn saturate(self, amount: NumberValueType) -> Self {
+        self.map_hsl(|[_, s, _]| *s = (*s + amount).clamp(0.0, 1.0))
+    }
+
+    /// Decrease the HSL saturation by the given amount in the range `0..=1`.
+    pub fn desaturate(self, amount: NumberValueType) -> Self {
+        self.saturate(-amount)
+    }
+
+    /// Mix two colors like `color-mix()` does.
+    /// `ratio` is the amount of `other` in the result, where `0` is `self` and `1` is `other`.
+    ///
+    /// The result is in the interpolation color space.
+    /// Returns `None` if the interpolation color space isn't supported.
+    pub fn mix(
+        self,
+        other: Self,
+        ratio: NumberValueType,
+        method: ColorInterpolationMethod,
+    ) -> Option<Self> {
+        let (space, hue_method) = match method {
+            ColorInterpolationMethod::Rectangular(space) => {
+                (ColorSpace::from_rectangular(space)?, None)
+            }
+            ColorInterpolationMethod::Polar(space, hue) => (
+                space.into(),
+                Some(hue.unwrap_or(HueInterpolationMethod::Shorter)),
+            ),
+        };
+        let t = ratio.clamp(0.0, 1.0);
+        let from = self.to_space(space);
+        let to = other.to_space(space);
+        let mut a = from.channels;
+        let mut b = to.channels;
+
+        let hue_channel = space.hue_channel();
+        if let (Some(i), Some(hue_method)) = (hue_channel, hue_method) {
+            // a powerless hue takes on the hue of the other color
+            if from.is_achromatic() {
+                a[i] = b[i];
+            } else if to.is_achromatic() {
+                b[i] = a[i];
+            }
+            let (h0, h1) = fixup_hues(a[i], b[i], hue_method);
+            a[i] = h0;
+            b[i] = h1;
+        }
+
+        let alpha = lerp(from.alpha, to.alpha, t);
+        let mut channels = [0.0; 3];
+        for i in 0..3 {
+            if Some(i) == hue_channel {
+                channels[i] = normalize_hue(lerp(a[i], b[i], t));
+                continue;
+            }
+
+            // interpolate with premultiplied alpha
+            let v = lerp(a[i] * from.alpha, b[i] * to.alpha, t);
+            channels[i] = if alpha == 0.0 { v } else { v / alpha };
+        }
+
+        Some(Self {
+            space,
+            channels,
+            alpha,
+        })
+    }
+}
+
+const ACHROMATIC_LCH_CHROMA: NumberValueType = 1e-4;
+const ACHROMATIC_OKLCH_CHROMA: NumberValueType = 1e-6;
+
+type Channels = [NumberValueType; 3];
+type Matrix = [Channels; 3];
+
+// https://drafts.csswg.org/css-color-4/#color-conversion-code
+#[rustfmt::skip]
+const LINEAR_SRGB_TO_XYZ_D65: Matrix = [
+    [0.41239079926595934, 0.357584339383878, 0.1804807884018343],
+    [0.21263900587151027, 0.715168678767756, 0.07219231536073371],
+    [0.01933081871559182, 0.11919477979462598, 0.9505321522496607],
+];
+#[rustfmt::skip]
+const XYZ_D65_TO_LINEAR_SRGB: Matrix = [
+    [3.2409699419045226, -1.537383177570094, -0.4986107602930034],
+    [-0.9692436362808796, 1.8759675015077202, 0.04155505740717559],
+    [0.05563007969699366, -0.20397695888897652, 1.0569715142428786],
+];
+#[rustfmt::skip]
+const XYZ_D65_TO_D50: Matrix = [
+    [1.0479298208405488, 0.022946793341019088, -0.05019222954313557],
+    [0.029627815688159344, 0.990434484573249, -0.01707382502938514],
+    [-0.009243058152591178, 0.015055144896577895, 0.7518742899580008],
+];
+#[rustfmt::skip]
+const XYZ_D50_TO_D65: Matrix = [
+    [0.9554734527042182, -0.023098536874261423, 0.0632593086610217],
+    [-0.028369706963208136, 1.0099954580058226, 0.021041398966943008],
+    [0.012314001688319899, -0.020507696433477912, 1.3303659366080753],
+];
+#[rustfmt::skip]
+const XYZ_D65_TO_LMS: Matrix = [
+    [0.819022437996703, 0.3619062600528904, -0.1288737815209879],
+    [0.0329836539323885, 0.9292868615863434, 0.0361446663506424],
+    [0.0481771893596242, 0.2642395317527308, 0.6335478284694309],
+];
+#[rustfmt::skip]
+const LMS_TO_XYZ_D65: Matrix = [
+    [1.2268798758459243, -0.5578149944602171, 0.2813910456659647],
+    [-0.0405757452148008, 1.112286803280317, -0.0717110580655164],
+    [-0.0763729366746601, -0.4214933324022432, 1.5869240198367816],
+];
+#[rustfmt::skip]
+const LMS_TO_OKLAB: Matrix = [
+    [0.210454268309314, 0.7936177747023054, -0.0040720430116193],
+    [1.9779985324311684, -2.42859224204858, 0.450593709617411],
+    [0.0259040424655478, 0.7827717124575296, -0.8086757549230774],
+];
+#[rustfmt::skip]
+const OKLAB_TO_LMS: Matrix = [
+    [1.0, 0.3963377773761749, 0.2158037573099136],
+    [1.0, -0.1055613458156586, -0.0638541728258133],
+    [1.0, -0.0894841775298119, -1.2914855480194092],
+];
+const D50_WHITE: Channels = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+const LAB_EPSILON: NumberValueType = 216.0 / 24389.0;
+const LAB_KAPPA: NumberValueType = 24389.0 / 27.0;
+
+fn multiply(m: &Matrix, v: Channels) -> Channels {
+    let row = |r: &Channels| r[0] * v[0] + r[1] * v[1] + r[2] * v[2];
+    [row(&m[0]), row(&m[1]), row(&m[2])]
+}
+
+fn lerp(a: NumberValueType, b: NumberValueType, t: NumberValueType) -> NumberValueType {
+    a + (b - a) * t
+}
+
+fn normalize_hue(h: NumberValueType) -> NumberValueType {
+    h.rem_euclid(360.0)
+}
+
+// https://drafts.csswg.org/css-color-4/#hue-interpolation
+fn fixup_hues(
+    h0: NumberValueType,
+    h1: NumberValueType,
+    method: HueInterpolationMethod,
+) -> (NumberValueType, NumberValueType) {
+    let (mut h0, mut h1) = (normalize_hue(h0), normalize_hue(h1));
+    let diff = h1 - h0;
+    match method {
+        HueInterpolationMethod::Shorter => {
+            if diff > 180.0 {
+                h0 += 360.0;
+            } else if diff < -180.0 {
+                h1 += 360.0;
+            }
+        }
+        HueInterpolationMethod::Longer => {
+            if 0.0 < diff && diff < 180.0 {
+                h0 += 360.0;
+            } else if -180.0 < diff && diff <= 0.0 {
+                h1 += 360.0;
+            }
+        }
+        HueInterpolationMethod::Increasing => {
+            if h1 < h0 {
+                h1 += 360.0;
+            }
+        }
+        HueInterpolationMethod::Decreasing => {
+            if h0 < h1 {
+                h0 += 360.0;
+            }
+        }
+    }
+    (h0, h1)
+}
+
+fn srgb_to_linear(c: NumberValueType) -> NumberValueType {
+    let abs = c.abs();
+    if abs <= 0.04045 {
+        c / 12.92
+    } else {
+        c.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: NumberValueType) -> NumberValueType {
+    let abs = c.abs();
+    if abs > 0.0031308 {
+        c.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+    } else {
+        c * 12.92
+    }
+}
+
+fn hsl_to_srgb([h, s, l]: Channels) -> Channels {
+    let f = |n: NumberValueType| {
+        let k = (n + h / 30.0).rem_euclid(12.0);
+        let a = s * l.min(1.0 - l);
+        l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+fn srgb_to_hsl([r, g, b]: Channels) -> Channels {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d = max - min;
+    let l = (min + max) / 2.0;
+    if d == 0.0 {
+        return [0.0, 0.0, l];
+    }
+
+    let mut s = if l == 0.0 || l == 1.0 {
+        0.0
+    } else {
+        (max - l) / l.min(1.0 - l)
+    };
+    let mut h = if max == r {
+        (g - b) / d + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    } * 60.0;
+    if s < 0.0 {
+        h += 180.0;
+        s = -s;
+    }
+    [normalize_hue(h), s, l]
+}
+
+fn hwb_to_srgb([h, w, b]: Channels) -> Channels {
+    if w + b >= 1.0 {
+        let gray = w / (w + b);
+        return [gray; 3];
+    }
+
+    let mut rgb = hsl_to_srgb([h, 1.0, 0.5]);
+    for c in &mut rgb {
+        *c = *c * (1.0 - w - b) + w;
+    }
+    rgb
+}
+
+fn srgb_to_hwb(rgb: Channels) -> Channels {
+    let [h, _, _] = srgb_to_hsl(rgb);
+    let [r, g, b] = rgb;
+    [h, r.min(g).min(b), 1.0 - r.max(g).max(b)]
+}
+
+fn rectangular_to_polar([l, a, b]: Channels, epsilon: NumberValueType) -> Channels {
+    let c = a.hypot(b);
+    if c < epsilon {
+        // the hue of achromatic colors is powerless
+        return [l, 0.0, 0.0];
+    }
+    [l, c, normalize_hue(b.atan2(a).to_degrees())]
+}
+
+fn polar_to_rectangular([l, c, h]: Channels) -> Channels {
+    let (sin, cos) = h.to_radians().sin_cos();
+    [l, c * cos, c * sin]
+}
+
+fn xyz_d50_to_lab(xyz: Channels) -> Channels {
+    let mut f = [0.0; 3];
+    for i in 0..3 {
+        let v = xyz[i] / D50_WHITE[i];
+        f[i] = if v > LAB_EPSILON {
+            v.cbrt()
+        } else {
+            (LAB_KAPPA * v + 16.0) / 116.0
+        };
+    }
+    [
+        116.0 * f[1] - 16.0,
+        500.0 * (f[0] - f[1]),
+        200.0 * (f[1] - f[2]),
+    ]
+}
+
+fn lab_to_xyz_d50([l, a, b]: Channels) -> Channels {
+    let f1 = (l + 16.0) / 116.0;
+    let f0 = a / 500.0 + f1;
+    let f2 = f1 - b / 200.0;
+    let component = |f: NumberValueType| {
+        let cubed = f.powi(3);
+        if cubed > LAB_EPSILON {
+            cubed
+        } else {
+            (116.0 * f - 16.0) / LAB_KAPPA
+        }
+    };
+    let y = if l > LAB_KAPPA * LAB_EPSILON {
+        f1.powi(3)
+    } else {
+        l / LAB_KAPPA
+    };
+    [
+        component(f0) * D50_WHITE[0],
+        y * D50_WHITE[1],
+        component(f2) * D50_WHITE[2],
+    ]
+}
+
+fn xyz_d65_to_oklab(xyz: Channels) -> Channels {
+    let lms = multiply(&XYZ_D65_TO_LMS, xyz);
+    multiply(&LMS_TO_OKLAB, [lms[0].cbrt(), lms[1].cbrt(), lms[2].cbrt()])
+}
+
+fn oklab_to_xyz_d65(oklab: Channels) -> Channels {
+    let lms = multiply(&OKLAB_TO_LMS, oklab);
+    multiply(
+        &LMS_TO_XYZ_D65,
+        [lms[0].powi(3), lms[1].powi(3), lms[2].powi(3)],
+    )
+}
+
+/// Only valid for sRGB based spaces.
+fn to_srgb(space: ColorSpace, channels: Channels) -> Channels {
+    match space {
+        ColorSpace::Hsl => hsl_to_srgb(channels),
+        ColorSpace::Hwb => hwb_to_srgb(channels),
+        _ => channels,
+    }
+}
+
+/// Only valid for sRGB based spaces.
+fn from_srgb(space: ColorSpace, rgb: Channels) -> Channels {
+    match space {
+        ColorSpace::Hsl => srgb_to_hsl(rgb),
+        ColorSpace::Hwb => srgb_to_hwb(rgb),
+        _ => rgb,
+    }
+}
+
+fn to_xyz_d65(space: ColorSpace, channels: Channels) -> Channels {
+    match space {
+        ColorSpace::Srgb | ColorSpace::Hsl | ColorSpace::Hwb => {
+            let [r, g, b] = to_srgb(space, channels);
+            to_xyz_d65(
+                ColorSpace::SrgbLinear,
+                [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b)],
+            )
+        }
+        ColorSpace::SrgbLinear => multiply(&LINEAR_SRGB_TO_XYZ_D65, channels),
+        ColorSpace::Lab => to_xyz_d65(ColorSpace::XyzD50, lab_to_xyz_d50(channels)),
+        ColorSpace::Lch => to_xyz_d65(ColorSpace::Lab, polar_to_rectangular(channels)),
+        ColorSpace::Oklab => oklab_to_xyz_d65(channels),
+        ColorSpace::Oklch => oklab_to_xyz_d65(polar_to_rectangular(channels)),
+        ColorSpace::XyzD50 => multiply(&XYZ_D50_TO_D65, channels),
+        ColorSpace::XyzD65 => channels,
+    }
+}
+
+fn from_xyz_d65(space: ColorSpace, xyz: Channels) -> Channels {
+    match space {
+        ColorSpace::Srgb | ColorSpace::Hsl | ColorSpace::Hwb => {
+            let [r, g, b] = from_xyz_d65(ColorSpace::SrgbLinear, xyz);
+            from_srgb(
+                space,
+                [linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b)],
+            )
+        }
+        ColorSpace::SrgbLinear => multiply(&XYZ_D65_TO_LINEAR_SRGB, xyz),
+        ColorSpace::Lab => xyz_d50_to_lab(from_xyz_d65(ColorSpace::XyzD50, xyz)),
+        ColorSpace::Lch => {
+            rectangular_to_polar(from_xyz_d65(ColorSpace::Lab, xyz), ACHROMATIC_LCH_CHROMA)
+        }
+        ColorSpace::Oklab => xyz_d65_to_oklab(xyz),
+        ColorSpace::Oklch => rectangular_to_polar(xyz_d65_to_oklab(xyz), ACHROMATIC_OKLCH_CHROMA),
+        ColorSpace::XyzD50 => multiply(&XYZ_D65_TO_D50, xyz),
+        ColorSpace::XyzD65 => xyz,
+    }
+}
@@ -1,4 +1,6 @@
-use super::{Number, Percentage};
+use super::{
+    AngleFunction, Calc, CalcDimension, CalcType, Number, NumberValueType, Percentage, Var,
+};
 use russ_internal::{CssValue, FromVariants, VariantConstructors};
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/angle
@@ -15,6 +17,138 @@ pub enum Angle {
 
     #[dimension(zero)]
     Zero,
+
+    #[constructor(skip)]
+    Calc(Box<Calc<Angle>>),
+    #[constructor(skip)]
+    Function(Box<AngleFunction>),
+    #[constructor(skip)]
+    Var(Var<Angle>),
+}
+impl Angle {
+    /// Size of the angle in degrees if it's known without evaluating a `calc()` or `var()`.
+    pub fn to_degrees(&self) -> Option<NumberValueType> {
+        match self {
+            Self::Deg(v) => v.value(),
+            Self::Grad(v) => v.value().map(|v| v * 0.9),
+            Self::Rad(v) => v.value().map(NumberValueType::to_degrees),
+            Self::Turn(v) => v.value().map(|v| v * 360.0),
+            Self::Zero => Some(0.0),
+            Self::Function(v) => v.resolve(),
+            Self::Calc(_) | Self::Var(_) => None,
+        }
+    }
+}
+impl CalcType for Angle {
+    fn to_calc_dimension(&self) -> Option<CalcDimension> {
+        let (unit, v) = match self {
+            Self::Deg(v) => ("deg", v),
+            Self::Grad(v) => ("grad", v),
+            Self::Rad(v) => ("rad", v),
+            Self::Turn(v) => ("turn", v),
+            Self::Zero => return Some(("deg", 0.0)),
+            Self::Function(v) => return v.resolve().map(|v| ("deg", v)),
+            Self::Calc(_) | Self::Var(_) => return None,
+        };
+        v.value().map(|v| (unit, v))
+    }
+
+    fn from_calc_dimension((unit, v): CalcDimension) -> Option<Self> {
+        let v = Number::from(v);
+        Some(match unit {
+            "deg" => Self::Deg(v),
+            "grad" => Self::Grad(v),
+            "rad" => Self::Rad(v),
+            "turn" => Self::Turn(v),
+            _ => return None,
+        })
+    }
+
+    fn canonicalize((unit, v): CalcDimension) -> CalcDimension {
+        match unit {
+            "grad" => ("deg", v * 0.9),
+            "rad" => ("deg", v.to_degrees()),
+            "turn" => ("deg", v * 360.0),
+            _ => (unit, v),
+        }
+    }
+
+    fn to_calc(&self) -> Option<Calc<Self>> {
+        match self {
+            Self::Calc(calc) => Some((**calc).clone()),
+            _ => None,
+        }
+    }
+
+    fn from_calc(calc: Calc<Self>) -> Self {
+        Self::Calc(Box::new(calc))
+    }
+}
+impl From<Calc<Angle>> for Angle {
+    fn from(v: Calc<Angle>) -> Self {
+        v.into_value()
+    }
+}
+impl From<AngleFunction> for Angle {
+    fn from(v: AngleFunction) -> Self {
+        match v.resolve() {
+            Some(v) => Self::Deg(v.into()),
+            None => Self::Function(Box::new(v)),
+        }
+    }
+}
+impl From<Var<Angle>> for Angle {
+    fn from(v: Var<Angle>) -> Self {
+        Self::Var(v)
+    }
+}
+
+/// Implement [`CalcType`] for an enum combining a dimension with percentages.
+macro_rules! impl_percentage_calc_type {
+    ($ty:ident, $variant:ident) => {
+        impl CalcType for $ty {
+            fn to_calc_dimension(&self) -> Option<CalcDimension> {
+                match self {
+                    Self::$variant(v) => v.to_calc_dimension(),
+                    Self::Percentage(v) => v.0.value().map(|v| ("%", v)),
+                    _ => None,
+                }
+            }
+
+            fn from_calc_dimension(dimension: CalcDimension) -> Option<Self> {
+                match dimension {
+                    ("%", v) => Some(Self::Percentage(v.into())),
+                    dimension => CalcType::from_calc_dimension(dimension).map(Self::$variant),
+                }
+            }
+
+            fn canonicalize(dimension: CalcDimension) -> CalcDimension {
+                match dimension {
+                    ("%", _) => dimension,
+                    dimension => $variant::canonicalize(dimension),
+                }
+            }
+
+            fn to_calc(&self) -> Option<Calc<Self>> {
+                match self {
+                    Self::$variant(v) => v
+                        .to_calc()
+                        .map(|calc| calc.map(&|v| Self::$variant(v.clone()))),
+                    Self::Calc(calc) => Some((**calc).clone()),
+                    _ => None,
+                }
+            }
+
+            fn from_calc(calc: Calc<Self>) -> Self {
+                Self::Calc(Box::new(calc))
+            }
+        }
+        impl From<Calc<$ty>> for $ty {
+            fn from(v: Calc<$ty>) -> Self {
+                v.into_value()
+            }
+        }
+    };
 }
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/angle-percentage
@@ -23,7 +157,9 @@ pub enum AnglePercentage {
     #[from_variant(into)]
     Angle(Angle),
     Percentage(Percentage),
+    Calc(Box<Calc<AnglePercentage>>),
 }
+impl_percentage_calc_type!(AnglePercentage, Angle);
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/flex_value
 #[derive(Clone, Debug, Eq, Hash, PartialEq, PartialOrd, CssValue)]
@@ -45,6 +181,51 @@ pub enum Frequency {
     Hz(Number),
     #[dimension(unit = "kHz")]
     Khz(Number),
+
+    #[constructor(skip)]
+    Calc(Box<Calc<Frequency>>),
+}
+impl CalcType for Frequency {
+    fn to_calc_dimension(&self) -> Option<CalcDimension> {
+        let (unit, v) = match self {
+            Self::Hz(v) => ("Hz", v),
+            Self::Khz(v) => ("kHz", v),
+            Self::Calc(_) => return None,
+        };
+        v.value().map(|v| (unit, v))
+    }
+
+    fn from_calc_dimension((unit, v): CalcDimension) -> Option<Self> {
+        let v = Number::from(v);
+        Some(match unit {
+            "Hz" => Self::Hz(v),
+            "kHz" => Self::Khz(v),
+            _ => return None,
+        })
+    }
+
+    fn canonicalize((unit, v): CalcDimension) -> CalcDimension {
+        match unit {
+            "kHz" => ("Hz", v * 1000.0),
+            _ => (unit, v),
+        }
+    }
+
+    fn to_calc(&self) -> Option<Calc<Self>> {
+        match self {
+            Self::Calc(calc) => Some((**calc).clone()),
+            _ => None,
+        }
+    }
+
+    fn from_calc(calc: Calc<Self>) -> Self {
+        Self::Calc(Box::new(calc))
+    }
+}
+impl From<Calc<Frequency>> for Frequency {
+    fn from(v: Calc<Frequency>) -> Self {
+        v.into_value()
+    }
 }
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/frequency-percentage
@@ -53,7 +234,9 @@ pub enum FrequencyPercentage {
     #[from_variant(into)]
     Frequency(Frequency),
     Percentage(Percentage),
+    Calc(Box<Calc<FrequencyPercentage>>),
 }
+impl_percentage_calc_type!(FrequencyPercentage, Frequency);
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/length
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, VariantConstructors)]
@@ -106,6 +289,103 @@ pub enum Length {
 
     #[dimension(zero)]
     Zero,
+
+    #[constructor(skip)]
+    Calc(Box<Calc<Length>>),
+    #[constructor(skip)]
+    Var(Var<Length>),
+}
+impl CalcType for Length {
+    fn to_calc_dimension(&self) -> Option<CalcDimension> {
+        let (unit, v) = match self {
+            Self::Cap(v) => ("cap", v),
+            Self::Ch(v) => ("ch", v),
+            Self::Em(v) => ("em", v),
+            Self::Ex(v) => ("ex", v),
+            Self::Ic(v) => ("ic", v),
+            Self::Lh(v) => ("lh", v),
+            Self::Rem(v) => ("rem", v),
+            Self::Rlh(v) => ("rlh", v),
+            Self::Vh(v) => ("vh", v),
+            Self::Vw(v) => ("vw", v),
+            Self::Vi(v) => ("vi", v),
+            Self::Vb(v) => ("vb", v),
+            Self::VMin(v) => ("vmin", v),
+            Self::VMax(v) => ("vmax", v),
+            Self::Px(v) => ("px", v),
+            Self::Cm(v) => ("cm", v),
+            Self::Mm(v) => ("mm", v),
+            Self::Q(v) => ("Q", v),
+            Self::In(v) => ("in", v),
+            Self::Pc(v) => ("pc", v),
+            Self::Pt(v) => ("pt", v),
+            Self::Zero => return Some(("px", 0.0)),
+            Self::Calc(_) | Self::Var(_) => return None,
+        };
+        v.value().map(|v| (unit, v))
+    }
+
+    fn from_calc_dimension((unit, v): CalcDimension) -> Option<Self> {
+        let v = Number::from(v);
+        Some(match unit {
+            "cap" => Self::Cap(v),
+            "ch" => Self::Ch(v),
+            "em" => Self::Em(v),
+            "ex" => Self::Ex(v),
+            "ic" => Self::Ic(v),
+            "lh" => Self::Lh(v),
+            "rem" => Self::Rem(v),
+            "rlh" => Self::Rlh(v),
+            "vh" => Self::Vh(v),
+            "vw" => Self::Vw(v),
+            "vi" => Self::Vi(v),
+            "vb" => Self::Vb(v),
+            "vmin" => Self::VMin(v),
+            "vmax" => Self::VMax(v),
+            "px" => Self::Px(v),
+            "cm" => Self::Cm(v),
+            "mm" => Self::Mm(v),
+            "Q" => Self::Q(v),
+            "in" => Self::In(v),
+            "pc" => Self::Pc(v),
+            "pt" => Self::Pt(v),
+            _ => return None,
+        })
+    }
+
+    fn canonicalize((unit, v): CalcDimension) -> CalcDimension {
+        let scale = match unit {
+            "cm" => 96.0 / 2.54,
+            "mm" => 96.0 / 25.4,
+            "Q" => 96.0 / 101.6,
+            "in" => 96.0,
+            "pc" => 16.0,
+            "pt" => 96.0 / 72.0,
+            _ => return (unit, v),
+        };
+        ("px", v * scale)
+    }
+
+    fn to_calc(&self) -> Option<Calc<Self>> {
+        match self {
+            Self::Calc(calc) => Some((**calc).clone()),
+            _ => None,
+        }
+    }
+
+    fn from_calc(calc: Calc<Self>) -> Self {
+        Self::Calc(Box::new(calc))
+    }
+}
+impl From<Calc<Length>> for Length {
+    fn from(v: Calc<Length>) -> Self {
+        v.into_value()
+    }
+}
+impl From<Var<Length>> for Length {
+    fn from(v: Var<Length>) -> Self {
+        Self::Var(v)
+    }
 }
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/length-percentage
@@ -114,7 +394,10 @@ pub enum LengthPercentage {
     #[from_variant(into)]
     Length(Length),
     Percentage(Percentage),
+    Calc(Box<Calc<LengthPercentage>>),
+    Var(Var<LengthPercentage>),
 }
+impl_percentage_calc_type!(LengthPercentage, Length);
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/resolution
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, VariantConstructors)]
@@ -125,12 +408,60 @@ pub enum Resolution {
     Dpcm(Number),
     #[dimension]
     Dppx(Number),
+
+    #[constructor(skip)]
+    Calc(Box<Calc<Resolution>>),
 }
 impl Resolution {
     pub fn x(v: impl Into<Number>) -> Self {
         Self::Dppx(v.into())
     }
 }
+impl CalcType for Resolution {
+    fn to_calc_dimension(&self) -> Option<CalcDimension> {
+        let (unit, v) = match self {
+            Self::Dppx(v) => ("dppx", v),
+            Self::Dpi(v) => ("dpi", v),
+            Self::Dpcm(v) => ("dpcm", v),
+            Self::Calc(_) => return None,
+        };
+        v.value().map(|v| (unit, v))
+    }
+
+    fn from_calc_dimension((unit, v): CalcDimension) -> Option<Self> {
+        let v = Number::from(v);
+        Some(match unit {
+            "dppx" => Self::Dppx(v),
+            "dpi" => Self::Dpi(v),
+            "dpcm" => Self::Dpcm(v),
+            _ => return None,
+        })
+    }
+
+    fn canonicalize((unit, v): CalcDimension) -> CalcDimension {
+        match unit {
+            "dpi" => ("dppx", v / 96.0),
+            "dpcm" => ("dppx", v * 2.54 / 96.0),
+            _ => (unit, v),
+        }
+    }
+
+    fn to_calc(&self) -> Option<Calc<Self>> {
+        match self {
+            Self::Calc(calc) => Some((**calc).clone()),
+            _ => None,
+        }
+    }
+
+    fn from_calc(calc: Calc<Self>) -> Self {
+        Self::Calc(Box::new(calc))
+    }
+}
+impl From<Calc<Resolution>> for Resolution {
+    fn from(v: Calc<Resolution>) -> Self {
+        v.into_value()
+    }
+}
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/time
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, VariantConstructors)]
@@ -139,6 +470,58 @@ pub enum Time {
     S(Number),
     #[dimension]
     Ms(Number),
+
+    #[constructor(skip)]
+    Calc(Box<Calc<Time>>),
+    #[constructor(skip)]
+    Var(Var<Time>),
+}
+impl CalcType for Time {
+    fn to_calc_dimension(&self) -> Option<CalcDimension> {
+        let (unit, v) = match self {
+            Self::S(v) => ("s", v),
+            Self::Ms(v) => ("ms", v),
+            Self::Calc(_) | Self::Var(_) => return None,
+        };
+        v.value().map(|v| (unit, v))
+    }
+
+    fn from_calc_dimension((unit, v): CalcDimension) -> Option<Self> {
+        let v = Number::from(v);
+        Some(match unit {
+            "s" => Self::S(v),
+            "ms" => Self::Ms(v),
+            _ => return None,
+        })
+    }
+
+    fn canonicalize((unit, v): CalcDimension) -> CalcDimension {
+        match unit {
+            "ms" => ("s", v * 0.001),
+            _ => (unit, v),
+        }
+    }
+
+    fn to_calc(&self) -> Option<Calc<Self>> {
+        match self {
+            Self::Calc(calc) => Some((**calc).clone()),
+            _ => None,
+        }
+    }
+
+    fn from_calc(calc: Calc<Self>) -> Self {
+        Self::Calc(Box::new(calc))
+    }
+}
+impl From<Calc<Time>> for Time {
+    fn from(v: Calc<Time>) -> Self {
+        v.into_value()
+    }
+}
+impl From<Var<Time>> for Time {
+    fn from(v: Var<Time>) -> Self {
+        Self::Var(v)
+    }
 }
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/time-percentage
@@ -147,4 +530,6 @@ pub enum TimePercentage {
     #[from_variant(into)]
     Time(Time),
     Percentage(Percentage),
+    Calc(Box<Calc<TimePercentage>>),
 }
+impl_percentage_calc_type!(TimePercentage, Time);
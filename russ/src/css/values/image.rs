@@ -1,13 +1,13 @@
 use super::{
-    Angle, AnglePercentage, CssString, Color, LengthPercentage, Percentage, Position, Resolution,
-    Url,
+    Angle, AnglePercentage, Color, ColorInterpolationMethod, CssString, LengthPercentage,
+    Percentage, Position, Resolution, Url,
 };
 use crate::css::Multiple;
 use russ_internal::{CssValue, FromVariants};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
 #[value]
-pub struct GradientColorStop<T>(Color, Option<T>, Option<T>);
+pub struct GradientColorStop<T>(pub(super) Color, pub(super) Option<T>, pub(super) Option<T>);
 impl<T> GradientColorStop<T> {
     pub fn build(color: Color, l1: Option<T>, l2: Option<T>) -> Option<Self> {
         if l1.is_none() && l2.is_some() {
@@ -47,7 +47,7 @@ where
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
 #[value(separator = ",")]
-pub struct GradientColorStopHint<T>(GradientColorStop<T>, Option<T>);
+pub struct GradientColorStopHint<T>(pub(super) GradientColorStop<T>, pub(super) Option<T>);
 impl<T> GradientColorStopHint<T> {
     pub const fn new(stop: GradientColorStop<T>, hint: Option<T>) -> Self {
         Self(stop, hint)
@@ -140,9 +140,10 @@ pub enum GradientEndingShape {
 // at least one of the three MUST be specified
 pub struct GradientRadialDefinition {
     shape: Option<GradientEndingShape>,
-    size: Option<GradientShapeSize>,
+    pub(super) size: Option<GradientShapeSize>,
     #[field(option, prefix = "at ")]
-    position: Option<Position>,
+    pub(super) position: Option<Position>,
+    interpolation: Option<ColorInterpolationMethod>,
 }
 impl GradientRadialDefinition {
     pub fn build(
@@ -157,9 +158,24 @@ impl GradientRadialDefinition {
                 shape,
                 size,
                 position,
+                interpolation: None,
             })
         }
     }
+
+    pub fn interpolated(interpolation: ColorInterpolationMethod) -> Self {
+        Self {
+            shape: None,
+            size: None,
+            position: None,
+            interpolation: Some(interpolation),
+        }
+    }
+
+    pub fn with_interpolation(mut self, interpolation: ColorInterpolationMethod) -> Self {
+        self.interpolation = Some(interpolation);
+        self
+    }
 }
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
@@ -167,42 +183,127 @@ impl GradientRadialDefinition {
 // at least one value must not be None
 pub struct GradientConicDefinition {
     #[field(option, prefix = "from ")]
-    from: Option<Angle>,
+    pub(super) from: Option<Angle>,
     #[field(option, prefix = "at ")]
-    at: Option<Position>,
+    pub(super) at: Option<Position>,
+    interpolation: Option<ColorInterpolationMethod>,
 }
 impl GradientConicDefinition {
     pub fn build(from: Option<Angle>, at: Option<Position>) -> Option<Self> {
         if matches!((&from, &at), (None, None)) {
             None
         } else {
-            Some(Self { from, at })
+            Some(Self {
+                from,
+                at,
+                interpolation: None,
+            })
+        }
+    }
+
+    pub fn interpolated(interpolation: ColorInterpolationMethod) -> Self {
+        Self {
+            from: None,
+            at: None,
+            interpolation: Some(interpolation),
         }
     }
+
+    pub fn with_interpolation(mut self, interpolation: ColorInterpolationMethod) -> Self {
+        self.interpolation = Some(interpolation);
+        self
+    }
+}
+
+// https://drafts.csswg.org/css-images-3/#typedef-side-or-corner
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum SideOrCorner {
+    #[keyword]
+    Left,
+    #[keyword]
+    Right,
+    #[keyword]
+    Top,
+    #[keyword]
+    Bottom,
+    #[keyword(value = "top left")]
+    TopLeft,
+    #[keyword(value = "top right")]
+    TopRight,
+    #[keyword(value = "bottom left")]
+    BottomLeft,
+    #[keyword(value = "bottom right")]
+    BottomRight,
+}
+
+// https://drafts.csswg.org/css-images-3/#linear-gradient-syntax
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum LinearDirection {
+    Angle(Angle),
+    #[value(prefix = "to ")]
+    To(SideOrCorner),
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+#[value]
+// at least one value must not be None
+pub struct GradientLinearDefinition {
+    pub(super) direction: Option<LinearDirection>,
+    interpolation: Option<ColorInterpolationMethod>,
+}
+impl GradientLinearDefinition {
+    pub fn build(
+        direction: Option<LinearDirection>,
+        interpolation: Option<ColorInterpolationMethod>,
+    ) -> Option<Self> {
+        if matches!((&direction, &interpolation), (None, None)) {
+            None
+        } else {
+            Some(Self {
+                direction,
+                interpolation,
+            })
+        }
+    }
+
+    pub fn with_interpolation(mut self, interpolation: ColorInterpolationMethod) -> Self {
+        self.interpolation = Some(interpolation);
+        self
+    }
 }
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/gradient
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
 pub enum Gradient {
-    /// side-or-corner (to left) isn't supported. Use angles instead.
     #[function(name = "linear-gradient")]
-    Linear(Option<Angle>, LinearColorStopList),
+    Linear(Option<GradientLinearDefinition>, LinearColorStopList),
 
     #[function(name = "radial-gradient")]
     Radial(Option<GradientRadialDefinition>, LinearColorStopList),
 
     #[function(name = "conic-gradient")]
     Conic(Option<GradientConicDefinition>, AngularColorStopList),
-    // TODO add support for repeating gradients which is just the same 3 again but the function name is "repeating-<name>"
+
+    #[function(name = "repeating-linear-gradient")]
+    RepeatingLinear(Option<GradientLinearDefinition>, LinearColorStopList),
+
+    #[function(name = "repeating-radial-gradient")]
+    RepeatingRadial(Option<GradientRadialDefinition>, LinearColorStopList),
+
+    #[function(name = "repeating-conic-gradient")]
+    RepeatingConic(Option<GradientConicDefinition>, AngularColorStopList),
 }
 impl Gradient {
-    pub fn linear<IT, SH, S>(angle: Option<Angle>, stops: IT, final_stop: S) -> Self
+    pub fn linear<IT, SH, S>(direction: Option<LinearDirection>, stops: IT, final_stop: S) -> Self
     where
         IT: IntoIterator<Item = SH>,
         SH: Into<LinearColorStopHint>,
         S: Into<LinearColorStop>,
     {
-        Self::Linear(angle, LinearColorStopList::build(stops, final_stop))
+        Self::Linear(
+            GradientLinearDefinition::build(direction, None),
+            LinearColorStopList::build(stops, final_stop),
+        )
     }
 
     pub fn radial<IT, SH, S>(
@@ -255,6 +356,78 @@ impl Gradient {
             AngularColorStopList::build(stops, final_stop),
         )
     }
+
+    /// Turn the gradient into its repeating version.
+    pub fn repeating(self) -> Self {
+        match self {
+            Self::Linear(definition, stops) => Self::RepeatingLinear(definition, stops),
+            Self::Radial(definition, stops) => Self::RepeatingRadial(definition, stops),
+            Self::Conic(definition, stops) => Self::RepeatingConic(definition, stops),
+            v => v,
+        }
+    }
+
+    /// Interpolate the colors of the gradient in the given color space.
+    pub fn interpolate(self, method: impl Into<ColorInterpolationMethod>) -> Self {
+        let method = method.into();
+        match self {
+            Self::Linear(definition, stops) => {
+                Self::Linear(Self::interpolate_linear(definition, method), stops)
+            }
+            Self::RepeatingLinear(definition, stops) => {
+                Self::RepeatingLinear(Self::interpolate_linear(definition, method), stops)
+            }
+            Self::Radial(definition, stops) => {
+                Self::Radial(Some(Self::interpolate_radial(definition, method)), stops)
+            }
+            Self::RepeatingRadial(definition, stops) => {
+                Self::RepeatingRadial(Some(Self::interpolate_radial(definition, method)), stops)
+            }
+            Self::Conic(definition, stops) => {
+                Self::Conic(Some(Self::interpolate_conic(definition, method)), stops)
+            }
+            Self::RepeatingConic(definition, stops) => {
+                Self::RepeatingConic(Some(Self::interpolate_conic(definition, method)), stops)
+            }
+        }
+    }
+
+    fn interpolate_linear(
+        definition: Option<GradientLinearDefinition>,
+        method: ColorInterpolationMethod,
+    ) -> Option<GradientLinearDefinition> {
+        match definition {
+            Some(definition) => Some(definition.with_interpolation(method)),
+            None => GradientLinearDefinition::build(None, Some(method)),
+        }
+    }
+
+    fn interpolate_radial(
+        definition: Option<GradientRadialDefinition>,
+        method: ColorInterpolationMethod,
+    ) -> GradientRadialDefinition {
+        match definition {
+            Some(definition) => definition.with_interpolation(method),
+            None => GradientRadialDefinition::interpolated(method),
+        }
+    }
+
+    fn interpolate_conic(
+        definition: Option<GradientConicDefinition>,
+        method: ColorInterpolationMethod,
+    ) -> GradientConicDefinition {
+        match definition {
+            Some(definition) => definition.with_interpolation(method),
+            None => GradientConicDefinition::interpolated(method),
+        }
+    }
+
+    pub fn is_repeating(&self) -> bool {
+        matches!(
+            self,
+            Self::RepeatingLinear(..) | Self::RepeatingRadial(..) | Self::RepeatingConic(..)
+        )
+    }
 }
 
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
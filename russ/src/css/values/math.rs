@@ -0,0 +1,517 @@
+use super::{
+    calc::common_unit, Angle, AnglePercentage, Calc, CalcDimension, CalcSum, CalcType, Frequency,
+    FrequencyPercentage, Integer, Length, LengthPercentage, Multiple, Number, NumberValueType,
+    Resolution, Time, TimePercentage,
+};
+use russ_internal::CssValue;
+
+fn finite(v: NumberValueType) -> Option<NumberValueType> {
+    if v.is_finite() {
+        Some(v)
+    } else {
+        None
+    }
+}
+
+/// Resolve the arguments to dimensions sharing the same unit.
+fn same_unit_values<'a, T, IT>(args: IT) -> Option<(&'static str, Vec<NumberValueType>)>
+where
+    T: CalcType + 'a,
+    IT: IntoIterator<Item = &'a CalcSum<T>>,
+{
+    let mut dimensions = args
+        .into_iter()
+        .map(CalcSum::to_dimension)
+        .collect::<Option<Vec<_>>>()?;
+    let unit = common_unit::<T>(&mut dimensions)?;
+    Some((unit, dimensions.into_iter().map(|(_, v)| v).collect()))
+}
+
+// https://developer.mozilla.org/en-US/docs/Web/CSS/round#rounding-strategy
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum RoundingStrategy {
+    #[keyword]
+    Nearest,
+    #[keyword]
+    Up,
+    #[keyword]
+    Down,
+    #[keyword]
+    ToZero,
+}
+impl RoundingStrategy {
+    fn round(self, v: NumberValueType) -> NumberValueType {
+        match self {
+            Self::Nearest => (v + 0.5).floor(),
+            Self::Up => v.ceil(),
+            Self::Down => v.floor(),
+            Self::ToZero => v.trunc(),
+        }
+    }
+}
+
+/// Math functions which resolve to the same type as their arguments.
+///
+/// <https://developer.mozilla.org/en-US/docs/Web/CSS/CSS_Functions#math_functions>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum MathFunction<T> {
+    #[function]
+    Min(Multiple<CalcSum<T>>),
+    #[function]
+    Max(Multiple<CalcSum<T>>),
+    #[function]
+    Clamp(CalcSum<T>, CalcSum<T>, CalcSum<T>),
+    #[function]
+    Round(Option<RoundingStrategy>, CalcSum<T>, CalcSum<T>),
+    #[function]
+    Mod(CalcSum<T>, CalcSum<T>),
+    #[function]
+    Rem(CalcSum<T>, CalcSum<T>),
+    #[function]
+    Abs(CalcSum<T>),
+    #[function]
+    Hypot(Multiple<CalcSum<T>>),
+}
+impl<T> MathFunction<T> {
+    pub fn min<IT, V>(values: IT) -> Option<Self>
+    where
+        IT: IntoIterator<Item = V>,
+        V: Into<CalcSum<T>>,
+    {
+        Multiple::build(values).map(Self::Min)
+    }
+
+    pub fn max<IT, V>(values: IT) -> Option<Self>
+    where
+        IT: IntoIterator<Item = V>,
+        V: Into<CalcSum<T>>,
+    {
+        Multiple::build(values).map(Self::Max)
+    }
+
+    pub fn clamp(
+        min: impl Into<CalcSum<T>>,
+        value: impl Into<CalcSum<T>>,
+        max: impl Into<CalcSum<T>>,
+    ) -> Self {
+        Self::Clamp(min.into(), value.into(), max.into())
+    }
+
+    pub fn round(
+        strategy: Option<RoundingStrategy>,
+        value: impl Into<CalcSum<T>>,
+        interval: impl Into<CalcSum<T>>,
+    ) -> Self {
+        Self::Round(strategy, value.into(), interval.into())
+    }
+
+    pub fn modulo(value: impl Into<CalcSum<T>>, divisor: impl Into<CalcSum<T>>) -> Self {
+        Self::Mod(value.into(), divisor.into())
+    }
+
+    pub fn rem(value: impl Into<CalcSum<T>>, divisor: impl Into<CalcSum<T>>) -> Self {
+        Self::Rem(value.into(), divisor.into())
+    }
+
+    pub fn abs(value: impl Into<CalcSum<T>>) -> Self {
+        Self::Abs(value.into())
+    }
+
+    pub fn hypot<IT, V>(values: IT) -> Option<Self>
+    where
+        IT: IntoIterator<Item = V>,
+        V: Into<CalcSum<T>>,
+    {
+        Multiple::build(values).map(Self::Hypot)
+    }
+
+    pub(crate) fn map<U>(&self, f: &impl Fn(&T) -> U) -> MathFunction<U> {
+        self.map_arguments(|v| v.map(f))
+    }
+
+    fn map_arguments<U>(&self, f: impl Fn(&CalcSum<T>) -> CalcSum<U>) -> MathFunction<U> {
+        let map_all = |values: &Multiple<CalcSum<T>>| {
+            Multiple::new(values.iter().map(&f).collect()).expect("mapping keeps the length")
+        };
+        match self {
+            Self::Min(values) => MathFunction::Min(map_all(values)),
+            Self::Max(values) => MathFunction::Max(map_all(values)),
+            Self::Clamp(min, v, max) => MathFunction::Clamp(f(min), f(v), f(max)),
+            Self::Round(strategy, v, interval) => MathFunction::Round(*strategy, f(v), f(interval)),
+            Self::Mod(v, divisor) => MathFunction::Mod(f(v), f(divisor)),
+            Self::Rem(v, divisor) => MathFunction::Rem(f(v), f(divisor)),
+            Self::Abs(v) => MathFunction::Abs(f(v)),
+            Self::Hypot(values) => MathFunction::Hypot(map_all(values)),
+        }
+    }
+}
+impl<T: CalcType> MathFunction<T> {
+    /// Dimension the function resolves to if all arguments can be simplified to dimensions with compatible units.
+    pub fn resolve(&self) -> Option<CalcDimension> {
+        let (unit, values) = match self {
+            Self::Min(values) | Self::Max(values) | Self::Hypot(values) => {
+                same_unit_values(values.iter())?
+            }
+            Self::Clamp(min, v, max) => same_unit_values(vec![min, v, max])?,
+            Self::Round(_, v, interval) => same_unit_values(vec![v, interval])?,
+            Self::Mod(v, divisor) | Self::Rem(v, divisor) => same_unit_values(vec![v, divisor])?,
+            Self::Abs(v) => same_unit_values(Some(v))?,
+        };
+
+        let value = match (self, values.as_slice()) {
+            (Self::Min(_), values) => values.iter().copied().fold(NumberValueType::NAN, f64::min),
+            (Self::Max(_), values) => values.iter().copied().fold(NumberValueType::NAN, f64::max),
+            (Self::Clamp(..), [min, v, max]) => v.min(*max).max(*min),
+            (Self::Round(strategy, ..), [v, interval]) => {
+                strategy
+                    .unwrap_or(RoundingStrategy::Nearest)
+                    .round(v / interval)
+                    * interval
+            }
+            (Self::Mod(..), [v, divisor]) => v - divisor * (v / divisor).floor(),
+            (Self::Rem(..), [v, divisor]) => v % divisor,
+            (Self::Abs(_), [v]) => v.abs(),
+            (Self::Hypot(_), values) => values.iter().map(|v| v * v).sum::<f64>().sqrt(),
+            _ => return None,
+        };
+        finite(value).map(|v| (unit, v))
+    }
+
+    pub(crate) fn simplify(&self) -> Self {
+        self.map_arguments(CalcSum::simplify)
+    }
+}
+
+/// Calculation of any type for the functions whose result doesn't depend on the type of their arguments.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum CalcArgument {
+    Number(CalcSum<Number>),
+    Angle(CalcSum<Angle>),
+    Frequency(CalcSum<Frequency>),
+    Length(CalcSum<LengthPercentage>),
+    Resolution(CalcSum<Resolution>),
+    Time(CalcSum<Time>),
+}
+impl CalcArgument {
+    fn to_dimension(&self) -> Option<CalcDimension> {
+        match self {
+            Self::Number(v) => v.to_dimension(),
+            Self::Angle(v) => v.to_dimension(),
+            Self::Frequency(v) => v.to_dimension(),
+            Self::Length(v) => v.to_dimension(),
+            Self::Resolution(v) => v.to_dimension(),
+            Self::Time(v) => v.to_dimension(),
+        }
+    }
+}
+
+macro_rules! impl_calc_argument_from {
+    ($target:ty: $($from:ty => $variant:ident,)*) => {
+        $(
+            impl From<$from> for $target {
+                fn from(v: $from) -> Self {
+                    Self::$variant(v.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_calc_argument_from! {
+    CalcArgument:
+    Number => Number,
+    Angle => Angle,
+    Frequency => Frequency,
+    Length => Length,
+    LengthPercentage => Length,
+    Resolution => Resolution,
+    Time => Time,
+}
+
+macro_rules! impl_calc_argument_from_calc {
+    ($target:ty: $($ty:ty => $variant:ident,)*) => {
+        $(
+            impl From<Calc<$ty>> for $target {
+                fn from(v: Calc<$ty>) -> Self {
+                    Self::$variant(v.into_sum())
+                }
+            }
+        )*
+    };
+}
+
+impl_calc_argument_from_calc! {
+    CalcArgument:
+    Number => Number,
+    Angle => Angle,
+    Frequency => Frequency,
+    LengthPercentage => Length,
+    Resolution => Resolution,
+    Time => Time,
+}
+
+/// Calculation of either a number or an angle, taken by the trigonometric functions.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum NumberOrAngle {
+    Number(CalcSum<Number>),
+    Angle(CalcSum<Angle>),
+}
+impl NumberOrAngle {
+    /// Value of the number or the angle in radians.
+    fn to_radians(&self) -> Option<NumberValueType> {
+        match self {
+            Self::Number(v) => v.to_dimension().map(|(_, v)| v),
+            Self::Angle(v) => match Angle::canonicalize(v.to_dimension()?) {
+                ("deg", v) => Some(v.to_radians()),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl_calc_argument_from! {
+    NumberOrAngle:
+    Number => Number,
+    Angle => Angle,
+}
+
+impl_calc_argument_from_calc! {
+    NumberOrAngle:
+    Number => Number,
+    Angle => Angle,
+}
+
+// https://developer.mozilla.org/en-US/docs/Web/CSS/calc-constant
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum MathConstant {
+    #[keyword]
+    E,
+    #[keyword]
+    Pi,
+    #[keyword]
+    Infinity,
+    #[keyword(value = "-infinity")]
+    NegativeInfinity,
+    #[keyword(value = "NaN")]
+    NaN,
+}
+impl MathConstant {
+    pub fn value(self) -> NumberValueType {
+        match self {
+            Self::E => std::f64::consts::E,
+            Self::Pi => std::f64::consts::PI,
+            Self::Infinity => NumberValueType::INFINITY,
+            Self::NegativeInfinity => NumberValueType::NEG_INFINITY,
+            Self::NaN => NumberValueType::NAN,
+        }
+    }
+}
+
+/// Math functions and constants which resolve to a number.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum NumberFunction {
+    Constant(MathConstant),
+    #[function]
+    Sign(CalcArgument),
+    #[function]
+    Sin(NumberOrAngle),
+    #[function]
+    Cos(NumberOrAngle),
+    #[function]
+    Tan(NumberOrAngle),
+    /// Rounds to the nearest integer, use [`MathFunction::Round`] to round to a different interval.
+    #[function]
+    Round(Option<RoundingStrategy>, CalcSum<Number>),
+    #[function]
+    Pow(CalcSum<Number>, CalcSum<Number>),
+    #[function]
+    Sqrt(CalcSum<Number>),
+    #[function]
+    Log(CalcSum<Number>, Option<CalcSum<Number>>),
+    #[function]
+    Exp(CalcSum<Number>),
+}
+impl NumberFunction {
+    pub fn sign(value: impl Into<CalcArgument>) -> Self {
+        Self::Sign(value.into())
+    }
+
+    pub fn sin(value: impl Into<NumberOrAngle>) -> Self {
+        Self::Sin(value.into())
+    }
+
+    pub fn cos(value: impl Into<NumberOrAngle>) -> Self {
+        Self::Cos(value.into())
+    }
+
+    pub fn tan(value: impl Into<NumberOrAngle>) -> Self {
+        Self::Tan(value.into())
+    }
+
+    pub fn round(strategy: Option<RoundingStrategy>, value: impl Into<CalcSum<Number>>) -> Self {
+        Self::Round(strategy, value.into())
+    }
+
+    pub fn pow(base: impl Into<CalcSum<Number>>, exponent: impl Into<CalcSum<Number>>) -> Self {
+        Self::Pow(base.into(), exponent.into())
+    }
+
+    pub fn sqrt(value: impl Into<CalcSum<Number>>) -> Self {
+        Self::Sqrt(value.into())
+    }
+
+    pub fn log(value: impl Into<CalcSum<Number>>, base: Option<CalcSum<Number>>) -> Self {
+        Self::Log(value.into(), base)
+    }
+
+    pub fn exp(value: impl Into<CalcSum<Number>>) -> Self {
+        Self::Exp(value.into())
+    }
+
+    /// Value of the function if all arguments can be simplified to constants.
+    pub fn resolve(&self) -> Option<NumberValueType> {
+        let number = CalcSum::to_dimension;
+        let value = match self {
+            Self::Constant(v) => v.value(),
+            Self::Sign(v) => {
+                let (_, v) = v.to_dimension()?;
+                if v == 0.0 {
+                    v
+                } else {
+                    v.signum()
+                }
+            }
+            Self::Sin(v) => v.to_radians()?.sin(),
+            Self::Cos(v) => v.to_radians()?.cos(),
+            Self::Tan(v) => v.to_radians()?.tan(),
+            Self::Round(strategy, v) => strategy
+                .unwrap_or(RoundingStrategy::Nearest)
+                .round(number(v)?.1),
+            Self::Pow(base, exponent) => number(base)?.1.powf(number(exponent)?.1),
+            Self::Sqrt(v) => number(v)?.1.sqrt(),
+            Self::Log(v, None) => number(v)?.1.ln(),
+            Self::Log(v, Some(base)) => number(v)?.1.log(number(base)?.1),
+            Self::Exp(v) => number(v)?.1.exp(),
+        };
+        finite(value)
+    }
+}
+impl From<MathConstant> for NumberFunction {
+    fn from(v: MathConstant) -> Self {
+        Self::Constant(v)
+    }
+}
+
+/// Arguments of `atan2()`, both of which have the same type.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum Atan2Arguments {
+    #[value(separator = ",")]
+    Number(CalcSum<Number>, CalcSum<Number>),
+    #[value(separator = ",")]
+    Angle(CalcSum<Angle>, CalcSum<Angle>),
+    #[value(separator = ",")]
+    Frequency(CalcSum<Frequency>, CalcSum<Frequency>),
+    #[value(separator = ",")]
+    Length(CalcSum<LengthPercentage>, CalcSum<LengthPercentage>),
+    #[value(separator = ",")]
+    Resolution(CalcSum<Resolution>, CalcSum<Resolution>),
+    #[value(separator = ",")]
+    Time(CalcSum<Time>, CalcSum<Time>),
+}
+impl Atan2Arguments {
+    fn resolve(&self) -> Option<NumberValueType> {
+        let (_, values) = match self {
+            Self::Number(y, x) => same_unit_values(vec![y, x])?,
+            Self::Angle(y, x) => same_unit_values(vec![y, x])?,
+            Self::Frequency(y, x) => same_unit_values(vec![y, x])?,
+            Self::Length(y, x) => same_unit_values(vec![y, x])?,
+            Self::Resolution(y, x) => same_unit_values(vec![y, x])?,
+            Self::Time(y, x) => same_unit_values(vec![y, x])?,
+        };
+        match values.as_slice() {
+            [y, x] => finite(y.atan2(*x).to_degrees()),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_atan2_arguments_from {
+    ($($from:ty => $variant:ident,)*) => {
+        $(
+            impl From<($from, $from)> for Atan2Arguments {
+                fn from((y, x): ($from, $from)) -> Self {
+                    Self::$variant(y.into(), x.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_atan2_arguments_from! {
+    Number => Number,
+    Angle => Angle,
+    Frequency => Frequency,
+    Length => Length,
+    LengthPercentage => Length,
+    Resolution => Resolution,
+    Time => Time,
+}
+
+/// Math functions which resolve to an angle.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum AngleFunction {
+    #[function]
+    Atan2(Atan2Arguments),
+}
+impl AngleFunction {
+    pub fn atan2<T>(y: T, x: T) -> Self
+    where
+        T: CalcType,
+        Atan2Arguments: From<(T, T)>,
+    {
+        Self::Atan2(Atan2Arguments::from((y, x)))
+    }
+
+    /// Size of the angle in degrees if all arguments can be simplified to dimensions with compatible units.
+    pub fn resolve(&self) -> Option<NumberValueType> {
+        match self {
+            Self::Atan2(arguments) => arguments.resolve(),
+        }
+    }
+}
+
+macro_rules! impl_from_math_function {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl From<MathFunction<$ty>> for $ty {
+                fn from(v: MathFunction<$ty>) -> Self {
+                    Calc::from(v).into_value()
+                }
+            }
+        )*
+    };
+}
+
+impl_from_math_function! {
+    Number,
+    Angle,
+    AnglePercentage,
+    Frequency,
+    FrequencyPercentage,
+    Length,
+    LengthPercentage,
+    Resolution,
+    Time,
+    TimePercentage,
+}
+
+impl From<MathFunction<Number>> for Integer {
+    fn from(v: MathFunction<Number>) -> Self {
+        Calc::from(v).into()
+    }
+}
+impl From<NumberFunction> for Integer {
+    fn from(v: NumberFunction) -> Self {
+        Calc::unary(Number::from(v)).into()
+    }
+}
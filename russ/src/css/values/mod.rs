@@ -2,20 +2,32 @@
 
 mod basic;
 mod calc;
+mod color;
+mod color_space;
 mod dimensions;
 mod image;
+mod math;
+mod named_color;
+mod ops;
 mod position;
+mod syntax;
+mod var;
 
 use super::{Multiple, OneToFour};
 pub use basic::*;
 pub use calc::*;
+pub use color::*;
+pub use color_space::*;
 pub use dimensions::*;
 pub use image::*;
+pub use math::*;
+pub use named_color::*;
 pub use position::*;
 use russ_internal::{
     CssValue, CssWriter, FromVariants, VariantConstructors, WriteResult, WriteValue,
 };
-use std::io::Write;
+pub use syntax::*;
+pub use var::*;
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
 pub enum BasicShapeRadius {
@@ -128,95 +140,6 @@ pub enum BlendMode {
     Luminosity,
 }
 
-pub type HexValueType = u32;
-
-// https://developer.mozilla.org/en-US/docs/Web/CSS/color_value
-#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
-pub enum Color {
-    #[value(prefix = "#", write_fn = "Self::write_hex")]
-    Hex(HexValueType),
-    #[function()]
-    Rgb {
-        r: NumberPercentage,
-        g: NumberPercentage,
-        b: NumberPercentage,
-        a: Option<NumberPercentage>,
-    },
-    #[function()]
-    Hsl {
-        h: Angle,
-        s: Percentage,
-        l: Percentage,
-        a: Option<NumberPercentage>,
-    },
-
-    #[keyword]
-    Transparent,
-    #[keyword(value = "currentcolor")]
-    CurrentColor,
-}
-impl Color {
-    /// Only 6 character hex colors are supported.
-    pub const fn hex(hex: HexValueType) -> Self {
-        Self::Hex(hex)
-    }
-
-    pub fn rgb(
-        r: impl Into<NumberPercentage>,
-        g: impl Into<NumberPercentage>,
-        b: impl Into<NumberPercentage>,
-    ) -> Self {
-        Self::Rgb {
-            r: r.into(),
-            g: g.into(),
-            b: b.into(),
-            a: None,
-        }
-    }
-
-    pub fn rgba(
-        r: impl Into<NumberPercentage>,
-        g: impl Into<NumberPercentage>,
-        b: impl Into<NumberPercentage>,
-        a: impl Into<NumberPercentage>,
-    ) -> Self {
-        Self::Rgb {
-            r: r.into(),
-            g: g.into(),
-            b: b.into(),
-            a: Some(a.into()),
-        }
-    }
-
-    pub fn hsl(h: impl Into<Angle>, s: impl Into<Percentage>, l: impl Into<Percentage>) -> Self {
-        Self::Hsl {
-            h: h.into(),
-            s: s.into(),
-            l: l.into(),
-            a: None,
-        }
-    }
-
-    pub fn hsla(
-        h: impl Into<Angle>,
-        s: impl Into<Percentage>,
-        l: impl Into<Percentage>,
-        a: impl Into<NumberPercentage>,
-    ) -> Self {
-        Self::Hsl {
-            h: h.into(),
-            s: s.into(),
-            l: l.into(),
-            a: Some(a.into()),
-        }
-    }
-
-    #[allow(clippy::trivially_copy_pass_by_ref)]
-    fn write_hex(f: &mut CssWriter, hex: &HexValueType) -> WriteResult {
-        write!(f, "{:06X}", hex)
-    }
-}
-
 // https://drafts.csswg.org/css-backgrounds-3/#typedef-box
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue, VariantConstructors)]
 pub enum CssBox {
@@ -284,33 +207,70 @@ pub enum ShapeBox {
 }
 
 // https://developer.mozilla.org/en-US/docs/Web/CSS/transform-function
-// #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, VariantConstructors)]
 pub enum TransformFunction {
-    // TODO
-    Matrix(),
-    Matrix3d(),
+    #[function]
+    Matrix(Number, Number, Number, Number, Number, Number),
+    #[constructor(skip)]
+    #[function(name = "matrix3d")]
+    Matrix3d(#[field(write_fn = "Self::write_matrix3d")] Box<[Number; 16]>),
+
+    #[function]
+    Perspective(Length),
 
-    Perspective(),
+    #[function]
+    Rotate(Angle),
+    #[function(name = "rotate3d")]
+    Rotate3d(Number, Number, Number, Angle),
+    #[function(name = "rotateX")]
+    RotateX(Angle),
+    #[function(name = "rotateY")]
+    RotateY(Angle),
+    #[function(name = "rotateZ")]
+    RotateZ(Angle),
 
-    Rotate(),
-    Rotate3d(),
-    RotateX(),
-    RotateY(),
-    RotateZ(),
+    #[function]
+    Scale(Number, Option<Number>),
+    #[function(name = "scale3d")]
+    Scale3d(Number, Number, Number),
+    #[function(name = "scaleX")]
+    ScaleX(Number),
+    #[function(name = "scaleY")]
+    ScaleY(Number),
+    #[function(name = "scaleZ")]
+    ScaleZ(Number),
 
-    Scale(),
-    Scale3d(),
-    ScaleX(),
-    ScaleY(),
-    ScaleZ(),
+    #[function]
+    Skew(Angle, Option<Angle>),
+    #[function(name = "skewX")]
+    SkewX(Angle),
+    #[function(name = "skewY")]
+    SkewY(Angle),
 
-    Skew(),
-    SkewX(),
-    SkewY(),
+    #[function]
+    Translate(LengthPercentage, Option<LengthPercentage>),
+    #[function(name = "translate3d")]
+    Translate3d(LengthPercentage, LengthPercentage, Length),
+    #[function(name = "translateX")]
+    TranslateX(LengthPercentage),
+    #[function(name = "translateY")]
+    TranslateY(LengthPercentage),
+    #[function(name = "translateZ")]
+    TranslateZ(Length),
+}
+impl TransformFunction {
+    /// Values are given in column-major order.
+    pub fn matrix3d(values: [Number; 16]) -> Self {
+        Self::Matrix3d(Box::new(values))
+    }
 
-    Translate(),
-    Translate3d(),
-    TranslateX(),
-    TranslateY(),
-    TranslateZ(),
+    fn write_matrix3d(f: &mut CssWriter, values: &[Number; 16]) -> WriteResult {
+        let (first, others) = values.split_first().expect("matrix is never empty");
+        first.write_value(f)?;
+        for v in others {
+            f.write_char(',')?;
+            v.write_value(f)?;
+        }
+        Ok(())
+    }
 }
@@ -0,0 +1,217 @@
+// Arithmetic operators for dimensions.
+//
+// Operations are built as a `calc()` expression which is simplified right away,
+// so operations on the same unit result in a plain dimension.
+
+use super::{
+    Angle, AnglePercentage, Calc, CalcProduct, CalcProductPart, CalcSumPart, CalcValue, Length,
+    LengthPercentage, Number, Percentage, Time, TimePercentage,
+};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+macro_rules! impl_sum_ops {
+    ($($lhs:ty, $rhs:ty => $output:ty;)*) => {
+        $(
+            impl Add<$rhs> for $lhs {
+                type Output = $output;
+
+                fn add(self, rhs: $rhs) -> Self::Output {
+                    Calc::<$output>::bin_add(self, rhs).into()
+                }
+            }
+            impl Sub<$rhs> for $lhs {
+                type Output = $output;
+
+                fn sub(self, rhs: $rhs) -> Self::Output {
+                    Calc::<$output>::bin_sub(self, rhs).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_sum_ops! {
+    Angle, Angle => Angle;
+    Angle, Percentage => AnglePercentage;
+    Angle, AnglePercentage => AnglePercentage;
+    AnglePercentage, Angle => AnglePercentage;
+    AnglePercentage, Percentage => AnglePercentage;
+    AnglePercentage, AnglePercentage => AnglePercentage;
+    Percentage, Angle => AnglePercentage;
+    Percentage, AnglePercentage => AnglePercentage;
+
+    Length, Length => Length;
+    Length, Percentage => LengthPercentage;
+    Length, LengthPercentage => LengthPercentage;
+    LengthPercentage, Length => LengthPercentage;
+    LengthPercentage, Percentage => LengthPercentage;
+    LengthPercentage, LengthPercentage => LengthPercentage;
+    Percentage, Length => LengthPercentage;
+    Percentage, LengthPercentage => LengthPercentage;
+
+    Time, Time => Time;
+    Time, Percentage => TimePercentage;
+    Time, TimePercentage => TimePercentage;
+    TimePercentage, Time => TimePercentage;
+    TimePercentage, Percentage => TimePercentage;
+    TimePercentage, TimePercentage => TimePercentage;
+    Percentage, Time => TimePercentage;
+    Percentage, TimePercentage => TimePercentage;
+}
+
+/// Multiplication and division by a number and negation.
+macro_rules! impl_product_ops {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl<V> Mul<V> for $ty
+            where
+                V: Into<CalcValue<Number>>,
+            {
+                type Output = Self;
+
+                fn mul(self, rhs: V) -> Self::Output {
+                    Calc::<Self>::bin_mul(self, rhs).into()
+                }
+            }
+            impl<V> Div<V> for $ty
+            where
+                V: Into<CalcValue<Number>>,
+            {
+                type Output = Self;
+
+                fn div(self, rhs: V) -> Self::Output {
+                    Calc::<Self>::bin_div(self, rhs).into()
+                }
+            }
+            impl Neg for $ty {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Calc::<Self>::bin_mul(self, -1).into()
+                }
+            }
+        )*
+    };
+}
+
+impl_product_ops!(
+    Angle,
+    AnglePercentage,
+    Length,
+    LengthPercentage,
+    Number,
+    Time,
+    TimePercentage,
+);
+
+impl<V> Add<V> for Number
+where
+    V: Into<CalcProduct<Number>>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: V) -> Self::Output {
+        Calc::bin_add(self, rhs).into()
+    }
+}
+impl<V> Sub<V> for Number
+where
+    V: Into<CalcProduct<Number>>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: V) -> Self::Output {
+        Calc::bin_sub(self, rhs).into()
+    }
+}
+
+impl Add for Percentage {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+impl Sub for Percentage {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+impl<V> Mul<V> for Percentage
+where
+    V: Into<CalcValue<Number>>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: V) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+impl<V> Div<V> for Percentage
+where
+    V: Into<CalcValue<Number>>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: V) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+impl Neg for Percentage {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+/// Operations on an expression extend it without simplifying it.
+impl<T, V> Add<V> for Calc<T>
+where
+    V: Into<CalcProduct<T>>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: V) -> Self::Output {
+        self.push_sum(CalcSumPart::Add(rhs.into()))
+    }
+}
+impl<T, V> Sub<V> for Calc<T>
+where
+    V: Into<CalcProduct<T>>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: V) -> Self::Output {
+        self.push_sum(CalcSumPart::Sub(rhs.into()))
+    }
+}
+impl<T, V> Mul<V> for Calc<T>
+where
+    V: Into<CalcValue<Number>>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: V) -> Self::Output {
+        self.push_product(CalcProductPart::Mul(rhs.into()))
+    }
+}
+impl<T, V> Div<V> for Calc<T>
+where
+    V: Into<CalcValue<Number>>,
+{
+    type Output = Self;
+
+    fn div(self, rhs: V) -> Self::Output {
+        self.push_product(CalcProductPart::Div(rhs.into()))
+    }
+}
+impl<T> Neg for Calc<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self * -1
+    }
+}
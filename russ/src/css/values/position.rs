@@ -69,8 +69,8 @@ where
 #[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
 #[value]
 pub struct Position {
-    horizontal: Option<PositionHorizontal>,
-    vertical: Option<PositionVertical>,
+    pub(super) horizontal: Option<PositionHorizontal>,
+    pub(super) vertical: Option<PositionVertical>,
 }
 impl Position {
     fn new(mut horizontal: Option<PositionHorizontal>, vertical: Option<PositionVertical>) -> Self {
@@ -115,3 +115,60 @@ impl Position {
         }
     }
 }
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum OriginHorizontal {
+    Anchor(PositionHorizontalAnchor),
+    #[from_variant(into)]
+    Offset(LengthPercentage),
+    #[keyword]
+    Center,
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum OriginVertical {
+    Anchor(PositionVerticalAnchor),
+    #[from_variant(into)]
+    Offset(LengthPercentage),
+    #[keyword]
+    Center,
+}
+
+/// Position with at most two values and without offsets from an anchor, as used by `transform-origin`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+#[value]
+pub struct OriginPosition {
+    horizontal: Option<OriginHorizontal>,
+    vertical: Option<OriginVertical>,
+}
+impl OriginPosition {
+    fn new(mut horizontal: Option<OriginHorizontal>, vertical: Option<OriginVertical>) -> Self {
+        // a single offset is always interpreted as the horizontal one, see `Position::new`
+        if horizontal.is_none() && matches!(&vertical, Some(OriginVertical::Offset(_))) {
+            horizontal = Some(OriginHorizontal::Center);
+        }
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+
+    pub fn center() -> Self {
+        Self::x(OriginHorizontal::Center)
+    }
+
+    pub fn x(horizontal: impl Into<OriginHorizontal>) -> Self {
+        Self::new(Some(horizontal.into()), None)
+    }
+
+    pub fn y(vertical: impl Into<OriginVertical>) -> Self {
+        Self::new(None, Some(vertical.into()))
+    }
+
+    pub fn xy(
+        horizontal: impl Into<OriginHorizontal>,
+        vertical: impl Into<OriginVertical>,
+    ) -> Self {
+        Self::new(Some(horizontal.into()), Some(vertical.into()))
+    }
+}
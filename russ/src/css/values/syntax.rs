@@ -0,0 +1,551 @@
+use super::{
+    Angle, AngleFunction, AnglePercentage, Atan2Arguments, Calc, CalcArgument, CalcProduct,
+    CalcProductPart, CalcSum, CalcSumPart, CalcValue, Color, ColorMixComponent, CrossFadeImage,
+    CrossFadeImageColor, CssString, CustomIdent, Frequency, FrequencyPercentage, Gradient,
+    GradientColorStop, GradientColorStopHint, GradientColorStopList, GradientConicDefinition,
+    GradientLinearDefinition, GradientRadialDefinition, GradientShapeSize, Image, ImageSetOption,
+    Integer, Length, LengthPercentage, LinearDirection, MathFunction, Number, NumberFunction,
+    NumberOrAngle, NumberPercentage, Percentage, Position, PositionHorizontal, PositionVertical,
+    Resolution, Time, TimePercentage, TransformFunction, Url,
+};
+use crate::css::Multiple;
+use russ_internal::{CssWriter, WriteResult, WriteValue};
+
+/// Value type which can be described by a syntax string.
+/// Used to register custom properties with `@property`.
+///
+/// <https://drafts.css-houdini.org/css-properties-values-api-1/#syntax-strings>
+pub trait ValueSyntax: WriteValue {
+    fn write_syntax(f: &mut CssWriter) -> WriteResult;
+
+    /// Whether the value can be computed without knowing anything about the element it's used on.
+    /// Only such values can be the initial value of a registered custom property.
+    ///
+    /// <https://drafts.css-houdini.org/css-properties-values-api-1/#computationally-independent>
+    fn is_computationally_independent(&self) -> bool;
+}
+
+macro_rules! impl_value_syntax {
+    ($($ty:ty => $syntax:literal,)*) => {
+        $(
+            impl ValueSyntax for $ty {
+                fn write_syntax(f: &mut CssWriter) -> WriteResult {
+                    f.write_str($syntax)
+                }
+
+                fn is_computationally_independent(&self) -> bool {
+                    self.is_independent()
+                }
+            }
+        )*
+    };
+}
+
+impl_value_syntax! {
+    Angle => "<angle>",
+    Color => "<color>",
+    CssString => "<string>",
+    CustomIdent => "<custom-ident>",
+    Image => "<image>",
+    Integer => "<integer>",
+    Length => "<length>",
+    LengthPercentage => "<length-percentage>",
+    Number => "<number>",
+    Percentage => "<percentage>",
+    Resolution => "<resolution>",
+    Time => "<time>",
+    TransformFunction => "<transform-function>",
+    Url => "<url>",
+}
+
+/// Comma separated list of values.
+impl<T: ValueSyntax> ValueSyntax for Multiple<T> {
+    fn write_syntax(f: &mut CssWriter) -> WriteResult {
+        T::write_syntax(f)?;
+        f.write_char('#')
+    }
+
+    fn is_computationally_independent(&self) -> bool {
+        self.iter().all(T::is_computationally_independent)
+    }
+}
+
+/// Checks whether a value contains a `var()` or a unit relative to the element.
+trait Independent {
+    fn is_independent(&self) -> bool;
+}
+
+/// Values which never depend on the element.
+macro_rules! impl_always_independent {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Independent for $ty {
+                fn is_independent(&self) -> bool {
+                    true
+                }
+            }
+        )*
+    };
+}
+
+impl_always_independent!(CssString, CustomIdent, Url);
+
+impl<T: Independent> Independent for Option<T> {
+    fn is_independent(&self) -> bool {
+        match self {
+            Some(v) => v.is_independent(),
+            None => true,
+        }
+    }
+}
+
+impl<T: Independent> Independent for Multiple<T> {
+    fn is_independent(&self) -> bool {
+        self.iter().all(T::is_independent)
+    }
+}
+
+impl<T: Independent> Independent for Calc<T> {
+    fn is_independent(&self) -> bool {
+        self.as_sum().is_independent()
+    }
+}
+
+impl<T: Independent> Independent for CalcSum<T> {
+    fn is_independent(&self) -> bool {
+        self.0.is_independent()
+            && self.1.iter().all(|part| match part {
+                CalcSumPart::Add(v) | CalcSumPart::Sub(v) => v.is_independent(),
+            })
+    }
+}
+
+impl<T: Independent> Independent for CalcProduct<T> {
+    fn is_independent(&self) -> bool {
+        self.0.is_independent()
+            && self.1.iter().all(|part| match part {
+                CalcProductPart::Mul(v) | CalcProductPart::Div(v) => v.is_independent(),
+            })
+    }
+}
+
+impl<T: Independent> Independent for CalcValue<T> {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Value(v) => v.is_independent(),
+            Self::CalcSum(v) => v.is_independent(),
+            Self::Function(v) => v.is_independent(),
+        }
+    }
+}
+
+impl<T: Independent> Independent for MathFunction<T> {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Min(values) | Self::Max(values) | Self::Hypot(values) => values.is_independent(),
+            Self::Clamp(min, v, max) => {
+                min.is_independent() && v.is_independent() && max.is_independent()
+            }
+            Self::Round(_, v, interval) => v.is_independent() && interval.is_independent(),
+            Self::Mod(v, divisor) | Self::Rem(v, divisor) => {
+                v.is_independent() && divisor.is_independent()
+            }
+            Self::Abs(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for CalcArgument {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Number(v) => v.is_independent(),
+            Self::Angle(v) => v.is_independent(),
+            Self::Frequency(v) => v.is_independent(),
+            Self::Length(v) => v.is_independent(),
+            Self::Resolution(v) => v.is_independent(),
+            Self::Time(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for NumberOrAngle {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Number(v) => v.is_independent(),
+            Self::Angle(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for NumberFunction {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Constant(_) => true,
+            Self::Sign(v) => v.is_independent(),
+            Self::Sin(v) | Self::Cos(v) | Self::Tan(v) => v.is_independent(),
+            Self::Round(_, v) => v.is_independent(),
+            Self::Pow(a, b) => a.is_independent() && b.is_independent(),
+            Self::Log(v, base) => v.is_independent() && base.is_independent(),
+            Self::Sqrt(v) | Self::Exp(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for AngleFunction {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Atan2(arguments) => arguments.is_independent(),
+        }
+    }
+}
+
+impl Independent for Atan2Arguments {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Number(y, x) => y.is_independent() && x.is_independent(),
+            Self::Angle(y, x) => y.is_independent() && x.is_independent(),
+            Self::Frequency(y, x) => y.is_independent() && x.is_independent(),
+            Self::Length(y, x) => y.is_independent() && x.is_independent(),
+            Self::Resolution(y, x) => y.is_independent() && x.is_independent(),
+            Self::Time(y, x) => y.is_independent() && x.is_independent(),
+        }
+    }
+}
+
+impl Independent for Number {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Value(_) => true,
+            Self::Calc(v) => v.is_independent(),
+            Self::Function(v) => v.is_independent(),
+            Self::Var(_) => false,
+        }
+    }
+}
+
+impl Independent for Integer {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Value(_) => true,
+            Self::Calc(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for Percentage {
+    fn is_independent(&self) -> bool {
+        self.0.is_independent()
+    }
+}
+
+impl Independent for NumberPercentage {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Number(v) => v.is_independent(),
+            Self::Percentage(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for Angle {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Deg(v) | Self::Grad(v) | Self::Rad(v) | Self::Turn(v) => v.is_independent(),
+            Self::Zero => true,
+            Self::Calc(v) => v.is_independent(),
+            Self::Function(v) => v.is_independent(),
+            Self::Var(_) => false,
+        }
+    }
+}
+
+impl Independent for Frequency {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Hz(v) | Self::Khz(v) => v.is_independent(),
+            Self::Calc(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for Length {
+    fn is_independent(&self) -> bool {
+        match self {
+            // relative to the font of the element or the root element
+            Self::Cap(_)
+            | Self::Ch(_)
+            | Self::Em(_)
+            | Self::Ex(_)
+            | Self::Ic(_)
+            | Self::Lh(_)
+            | Self::Rem(_)
+            | Self::Rlh(_) => false,
+            Self::Vh(v)
+            | Self::Vw(v)
+            | Self::Vi(v)
+            | Self::Vb(v)
+            | Self::VMin(v)
+            | Self::VMax(v)
+            | Self::Px(v)
+            | Self::Cm(v)
+            | Self::Mm(v)
+            | Self::Q(v)
+            | Self::In(v)
+            | Self::Pc(v)
+            | Self::Pt(v) => v.is_independent(),
+            Self::Zero => true,
+            Self::Calc(v) => v.is_independent(),
+            Self::Var(_) => false,
+        }
+    }
+}
+
+impl Independent for Resolution {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Dpi(v) | Self::Dpcm(v) | Self::Dppx(v) => v.is_independent(),
+            Self::Calc(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for Time {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::S(v) | Self::Ms(v) => v.is_independent(),
+            Self::Calc(v) => v.is_independent(),
+            Self::Var(_) => false,
+        }
+    }
+}
+
+impl Independent for AnglePercentage {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Angle(v) => v.is_independent(),
+            Self::Percentage(v) => v.is_independent(),
+            Self::Calc(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for FrequencyPercentage {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Frequency(v) => v.is_independent(),
+            Self::Percentage(v) => v.is_independent(),
+            Self::Calc(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for LengthPercentage {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Length(v) => v.is_independent(),
+            Self::Percentage(v) => v.is_independent(),
+            Self::Calc(v) => v.is_independent(),
+            Self::Var(_) => false,
+        }
+    }
+}
+
+impl Independent for TimePercentage {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Time(v) => v.is_independent(),
+            Self::Percentage(v) => v.is_independent(),
+            Self::Calc(v) => v.is_independent(),
+        }
+    }
+}
+
+impl Independent for Color {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Rgb { r, g, b, a } => {
+                r.is_independent() && g.is_independent() && b.is_independent() && a.is_independent()
+            }
+            Self::Hsl { h, s, l, a } => {
+                h.is_independent() && s.is_independent() && l.is_independent() && a.is_independent()
+            }
+            Self::Hwb { h, w, b, alpha } => {
+                h.is_independent()
+                    && w.is_independent()
+                    && b.is_independent()
+                    && alpha.is_independent()
+            }
+            Self::Lab { l, a, b, alpha } | Self::Oklab { l, a, b, alpha } => {
+                l.is_independent()
+                    && a.is_independent()
+                    && b.is_independent()
+                    && alpha.is_independent()
+            }
+            Self::Lch { l, c, h, alpha } | Self::Oklch { l, c, h, alpha } => {
+                l.is_independent()
+                    && c.is_independent()
+                    && h.is_independent()
+                    && alpha.is_independent()
+            }
+            Self::Predefined {
+                c1, c2, c3, alpha, ..
+            } => {
+                c1.is_independent()
+                    && c2.is_independent()
+                    && c3.is_independent()
+                    && alpha.is_independent()
+            }
+            Self::Hex(_)
+            | Self::Named(_)
+            | Self::System(_)
+            | Self::Transparent
+            | Self::CurrentColor => true,
+            Self::Mix(mix) => mix.first.is_independent() && mix.second.is_independent(),
+            // the channels are only known once the origin color is computed
+            Self::Relative(_) => false,
+            Self::Var(_) => false,
+        }
+    }
+}
+
+impl Independent for ColorMixComponent {
+    fn is_independent(&self) -> bool {
+        self.color.is_independent() && self.percentage.is_independent()
+    }
+}
+
+impl Independent for Position {
+    fn is_independent(&self) -> bool {
+        let horizontal = match &self.horizontal {
+            Some(PositionHorizontal::Offset(_, v)) => v.is_independent(),
+            _ => true,
+        };
+        let vertical = match &self.vertical {
+            Some(PositionVertical::Offset(_, v)) => v.is_independent(),
+            _ => true,
+        };
+        horizontal && vertical
+    }
+}
+
+impl<T: Independent> Independent for GradientColorStop<T> {
+    fn is_independent(&self) -> bool {
+        self.0.is_independent() && self.1.is_independent() && self.2.is_independent()
+    }
+}
+
+impl<T: Independent> Independent for GradientColorStopHint<T> {
+    fn is_independent(&self) -> bool {
+        self.0.is_independent() && self.1.is_independent()
+    }
+}
+
+impl<T: Independent> Independent for GradientColorStopList<T> {
+    fn is_independent(&self) -> bool {
+        self.0.iter().all(GradientColorStopHint::is_independent) && self.1.is_independent()
+    }
+}
+
+impl Independent for GradientShapeSize {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Size(a, b) => a.is_independent() && b.is_independent(),
+            _ => true,
+        }
+    }
+}
+
+impl Independent for GradientLinearDefinition {
+    fn is_independent(&self) -> bool {
+        match &self.direction {
+            Some(LinearDirection::Angle(v)) => v.is_independent(),
+            _ => true,
+        }
+    }
+}
+
+impl Independent for GradientRadialDefinition {
+    fn is_independent(&self) -> bool {
+        self.size.is_independent() && self.position.is_independent()
+    }
+}
+
+impl Independent for GradientConicDefinition {
+    fn is_independent(&self) -> bool {
+        self.from.is_independent() && self.at.is_independent()
+    }
+}
+
+impl Independent for Gradient {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Linear(definition, stops) | Self::RepeatingLinear(definition, stops) => {
+                definition.is_independent() && stops.is_independent()
+            }
+            Self::Radial(definition, stops) | Self::RepeatingRadial(definition, stops) => {
+                definition.is_independent() && stops.is_independent()
+            }
+            Self::Conic(definition, stops) | Self::RepeatingConic(definition, stops) => {
+                definition.is_independent() && stops.is_independent()
+            }
+        }
+    }
+}
+
+impl Independent for ImageSetOption {
+    fn is_independent(&self) -> bool {
+        self.1.is_independent()
+    }
+}
+
+impl Independent for CrossFadeImage {
+    fn is_independent(&self) -> bool {
+        self.0.is_independent()
+            && match &self.1 {
+                CrossFadeImageColor::Image(v) => v.is_independent(),
+                CrossFadeImageColor::Color(v) => v.is_independent(),
+            }
+    }
+}
+
+impl Independent for Image {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Url(_) => true,
+            Self::Gradient(v) => v.is_independent(),
+            Self::Image(_, _, color) => color.is_independent(),
+            Self::ImageSet(options) => options.is_independent(),
+            Self::CrossFade(images) => images.is_independent(),
+        }
+    }
+}
+
+impl Independent for TransformFunction {
+    fn is_independent(&self) -> bool {
+        match self {
+            Self::Matrix(a, b, c, d, e, f) => [a, b, c, d, e, f].iter().all(|v| v.is_independent()),
+            Self::Matrix3d(values) => values.iter().all(Number::is_independent),
+            Self::Perspective(v) | Self::TranslateZ(v) => v.is_independent(),
+            Self::Rotate(v)
+            | Self::RotateX(v)
+            | Self::RotateY(v)
+            | Self::RotateZ(v)
+            | Self::SkewX(v)
+            | Self::SkewY(v) => v.is_independent(),
+            Self::Rotate3d(x, y, z, a) => {
+                x.is_independent() && y.is_independent() && z.is_independent() && a.is_independent()
+            }
+            Self::Scale(x, y) => x.is_independent() && y.is_independent(),
+            Self::Scale3d(x, y, z) => {
+                x.is_independent() && y.is_independent() && z.is_independent()
+            }
+            Self::ScaleX(v) | Self::ScaleY(v) | Self::ScaleZ(v) => v.is_independent(),
+            Self::Skew(x, y) => x.is_independent() && y.is_independent(),
+            Self::Translate(x, y) => x.is_independent() && y.is_independent(),
+            Self::Translate3d(x, y, z) => {
+                x.is_independent() && y.is_independent() && z.is_independent()
+            }
+            Self::TranslateX(v) | Self::TranslateY(v) => v.is_independent(),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use super::DashedIdent;
+use russ_internal::CssValue;
+
+/// Reference to a custom property holding a value of type `T`.
+/// Value types which accept a `var()` reference convert from `Var<Self>`.
+///
+/// <https://drafts.csswg.org/css-variables/#using-variables>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+#[function]
+pub struct Var<T> {
+    pub name: DashedIdent,
+    pub fallback: Option<Box<T>>,
+}
+impl<T> Var<T> {
+    pub fn new(name: impl Into<DashedIdent>) -> Self {
+        Self {
+            name: name.into(),
+            fallback: None,
+        }
+    }
+
+    /// Value used if the custom property isn't defined.
+    pub fn with_fallback(mut self, fallback: impl Into<T>) -> Self {
+        self.fallback = Some(Box::new(fallback.into()));
+        self
+    }
+}
@@ -0,0 +1,215 @@
+/// <https://drafts.csswg.org/css-contain-3/#container-rule>
+use super::{Declaration, DeclarationBlock};
+use crate::css::{
+    values::{CustomIdent, Length, Ratio},
+    Multiple,
+};
+use russ_internal::{CssValue, CssWriter, WriteResult, WriteValue};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum ContainerOrientation {
+    #[keyword]
+    Portrait,
+    #[keyword]
+    Landscape,
+}
+
+/// <https://drafts.csswg.org/css-contain-3/#size-container>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum ContainerSizeFeature {
+    #[value(prefix = "(width:", suffix = ")")]
+    Width(Length),
+    #[value(prefix = "(min-width:", suffix = ")")]
+    MinWidth(Length),
+    #[value(prefix = "(max-width:", suffix = ")")]
+    MaxWidth(Length),
+    #[value(prefix = "(height:", suffix = ")")]
+    Height(Length),
+    #[value(prefix = "(min-height:", suffix = ")")]
+    MinHeight(Length),
+    #[value(prefix = "(max-height:", suffix = ")")]
+    MaxHeight(Length),
+    #[value(prefix = "(inline-size:", suffix = ")")]
+    InlineSize(Length),
+    #[value(prefix = "(min-inline-size:", suffix = ")")]
+    MinInlineSize(Length),
+    #[value(prefix = "(max-inline-size:", suffix = ")")]
+    MaxInlineSize(Length),
+    #[value(prefix = "(block-size:", suffix = ")")]
+    BlockSize(Length),
+    #[value(prefix = "(min-block-size:", suffix = ")")]
+    MinBlockSize(Length),
+    #[value(prefix = "(max-block-size:", suffix = ")")]
+    MaxBlockSize(Length),
+    #[value(prefix = "(aspect-ratio:", suffix = ")")]
+    AspectRatio(Ratio),
+    #[value(prefix = "(min-aspect-ratio:", suffix = ")")]
+    MinAspectRatio(Ratio),
+    #[value(prefix = "(max-aspect-ratio:", suffix = ")")]
+    MaxAspectRatio(Ratio),
+    #[value(prefix = "(orientation:", suffix = ")")]
+    Orientation(ContainerOrientation),
+}
+
+/// <https://drafts.csswg.org/css-contain-3/#typedef-container-condition>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ContainerCondition {
+    Feature(ContainerSizeFeature),
+    Not(Box<ContainerCondition>),
+    And(Box<ContainerCondition>, Multiple<ContainerCondition>),
+    Or(Box<ContainerCondition>, Multiple<ContainerCondition>),
+}
+impl ContainerCondition {
+    pub fn negate(self) -> Self {
+        Self::Not(Box::new(self))
+    }
+
+    pub fn and(self, other: impl Into<Self>) -> Self {
+        match self {
+            Self::And(first, others) => {
+                let mut others: Vec<_> = others.into();
+                others.push(other.into());
+                Self::And(
+                    first,
+                    Multiple::new(others).expect("pushing keeps it non-empty"),
+                )
+            }
+            v => Self::And(Box::new(v), Multiple::one(other)),
+        }
+    }
+
+    pub fn or(self, other: impl Into<Self>) -> Self {
+        match self {
+            Self::Or(first, others) => {
+                let mut others: Vec<_> = others.into();
+                others.push(other.into());
+                Self::Or(
+                    first,
+                    Multiple::new(others).expect("pushing keeps it non-empty"),
+                )
+            }
+            v => Self::Or(Box::new(v), Multiple::one(other)),
+        }
+    }
+
+    /// Condition which is true if all of the conditions are.
+    /// A single condition is returned as is.
+    ///
+    /// Returns `None` if there are no conditions.
+    pub fn all<IT, C>(conditions: IT) -> Option<Self>
+    where
+        IT: IntoIterator<Item = C>,
+        C: Into<Self>,
+    {
+        let mut conditions = conditions.into_iter().map(Into::into);
+        let first = conditions.next()?;
+        Some(conditions.fold(first, Self::and))
+    }
+
+    /// Condition which is true if any of the conditions is.
+    /// A single condition is returned as is.
+    ///
+    /// Returns `None` if there are no conditions.
+    pub fn any<IT, C>(conditions: IT) -> Option<Self>
+    where
+        IT: IntoIterator<Item = C>,
+        C: Into<Self>,
+    {
+        let mut conditions = conditions.into_iter().map(Into::into);
+        let first = conditions.next()?;
+        Some(conditions.fold(first, Self::or))
+    }
+
+    fn write_in_parens(&self, f: &mut CssWriter) -> WriteResult {
+        if let Self::Feature(feature) = self {
+            feature.write_value(f)
+        } else {
+            f.write_char('(')?;
+            self.write_value(f)?;
+            f.write_char(')')
+        }
+    }
+
+    fn write_joined(
+        f: &mut CssWriter,
+        first: &Self,
+        others: &Multiple<Self>,
+        separator: &str,
+    ) -> WriteResult {
+        first.write_in_parens(f)?;
+        for v in others.iter() {
+            f.write_str(separator)?;
+            v.write_in_parens(f)?;
+        }
+        Ok(())
+    }
+}
+impl WriteValue for ContainerCondition {
+    fn write_value(&self, f: &mut CssWriter) -> WriteResult {
+        match self {
+            Self::Feature(feature) => feature.write_value(f),
+            Self::Not(condition) => {
+                f.write_str("not ")?;
+                condition.write_in_parens(f)
+            }
+            Self::And(first, others) => Self::write_joined(f, first, others, " and "),
+            Self::Or(first, others) => Self::write_joined(f, first, others, " or "),
+        }
+    }
+}
+impl From<ContainerSizeFeature> for ContainerCondition {
+    fn from(v: ContainerSizeFeature) -> Self {
+        Self::Feature(v)
+    }
+}
+
+/// Prelude of a `@container` rule.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+#[value]
+pub struct ContainerQuery {
+    pub name: Option<CustomIdent>,
+    pub condition: ContainerCondition,
+}
+impl ContainerQuery {
+    /// Query the nearest ancestor container.
+    pub fn nearest(condition: impl Into<ContainerCondition>) -> Self {
+        Self {
+            name: None,
+            condition: condition.into(),
+        }
+    }
+
+    /// Query the nearest ancestor container with the given name.
+    pub fn named(name: impl Into<CustomIdent>, condition: impl Into<ContainerCondition>) -> Self {
+        Self {
+            name: Some(name.into()),
+            condition: condition.into(),
+        }
+    }
+}
+
+/// Declarations which only apply if the container query matches.
+#[derive(Clone, Debug, Hash)]
+pub struct ContainerRule {
+    pub query: ContainerQuery,
+    pub block: DeclarationBlock,
+}
+impl ContainerRule {
+    pub fn build<D: Into<Declaration>>(
+        query: ContainerQuery,
+        declarations: impl IntoIterator<Item = D>,
+    ) -> Self {
+        Self {
+            query,
+            block: DeclarationBlock::build(declarations),
+        }
+    }
+
+    pub fn write_container_rule(&self, f: &mut CssWriter, selector: &str) -> WriteResult {
+        f.write_str("@container ")?;
+        self.query.write_value(f)?;
+        f.write_char('{')?;
+        self.block.write_block_with_selector(f, selector)?;
+        f.write_char('}')
+    }
+}
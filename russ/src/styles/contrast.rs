@@ -0,0 +1,124 @@
+/// <https://www.w3.org/TR/WCAG21/#contrast-minimum>
+use super::{DeclarationBlock, RuleSet, Styles};
+use crate::css::{
+    props::{Background, BackgroundColor, ForegroundColor},
+    values::{contrast_ratio, Color, NumberValueType},
+};
+
+/// WCAG conformance level for the contrast between text and its background.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ContrastLevel {
+    /// Level AA for normal text.
+    Aa,
+    /// Level AA for large text.
+    AaLarge,
+    /// Level AAA for normal text.
+    Aaa,
+    /// Level AAA for large text.
+    AaaLarge,
+}
+impl ContrastLevel {
+    pub fn min_ratio(self) -> NumberValueType {
+        match self {
+            Self::Aa => 4.5,
+            Self::AaLarge => 3.0,
+            Self::Aaa => 7.0,
+            Self::AaaLarge => 4.5,
+        }
+    }
+}
+
+/// Pair of `color` and `background-color` which doesn't have enough contrast.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContrastIssue {
+    pub foreground: Color,
+    pub background: Color,
+    pub ratio: NumberValueType,
+    pub min_ratio: NumberValueType,
+}
+
+/// Check the `color` and `background-color` declarations against the WCAG contrast requirements.
+///
+/// Only colors which can be resolved at build time are checked.
+pub trait CheckContrast {
+    fn check_contrast(&self, level: ContrastLevel) -> Vec<ContrastIssue>;
+}
+impl CheckContrast for DeclarationBlock {
+    fn check_contrast(&self, level: ContrastLevel) -> Vec<ContrastIssue> {
+        let mut colors = EffectiveColors::default();
+        colors.apply(self);
+        colors.check(level).into_iter().collect()
+    }
+}
+impl CheckContrast for RuleSet {
+    /// Container rules are checked together with the declarations they override.
+    fn check_contrast(&self, level: ContrastLevel) -> Vec<ContrastIssue> {
+        let mut base = EffectiveColors::default();
+        base.apply(&self.block);
+        let mut issues: Vec<_> = base.check(level).into_iter().collect();
+        for container in &self.containers {
+            let mut colors = base;
+            if colors.apply(&container.block) {
+                issues.extend(colors.check(level));
+            }
+        }
+        issues
+    }
+}
+impl CheckContrast for Styles {
+    fn check_contrast(&self, level: ContrastLevel) -> Vec<ContrastIssue> {
+        self.rule_sets()
+            .iter()
+            .flat_map(|rule_set| rule_set.check_contrast(level))
+            .collect()
+    }
+}
+
+/// Colors which end up being used along with whether they're `!important`.
+#[derive(Clone, Copy, Default)]
+struct EffectiveColors<'a> {
+    foreground: Option<(&'a Color, bool)>,
+    background: Option<(&'a Color, bool)>,
+}
+impl<'a> EffectiveColors<'a> {
+    /// Returns whether any of the colors were declared in the block.
+    fn apply(&mut self, block: &'a DeclarationBlock) -> bool {
+        let mut declared = false;
+        for decl in block.declarations() {
+            let (slot, color) = if let Some(ForegroundColor(color)) = decl.downcast_ref() {
+                (&mut self.foreground, color)
+            } else if let Some(BackgroundColor(color)) = decl.downcast_ref() {
+                (&mut self.background, color)
+            } else if let Some(Background(_, final_layer)) = decl.downcast_ref() {
+                (&mut self.background, &final_layer.color.0)
+            } else {
+                continue;
+            };
+
+            // later declarations win unless the earlier one is important
+            let important = decl.is_important();
+            if important || !slot.is_some_and(|(_, important)| important) {
+                *slot = Some((color, important));
+                declared = true;
+            }
+        }
+        declared
+    }
+
+    fn check(&self, level: ContrastLevel) -> Option<ContrastIssue> {
+        let (foreground, _) = self.foreground?;
+        let (background, _) = self.background?;
+        let ratio = contrast_ratio(foreground, background)?;
+        let min_ratio = level.min_ratio();
+        if ratio < min_ratio {
+            Some(ContrastIssue {
+                foreground: foreground.clone(),
+                background: background.clone(),
+                ratio,
+                min_ratio,
+            })
+        } else {
+            None
+        }
+    }
+}
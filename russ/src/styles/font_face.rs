@@ -0,0 +1,287 @@
+/// <https://drafts.csswg.org/css-fonts-4/#font-face-rule>
+use super::{CssKey, StyleSheetRef};
+use crate::css::{
+    props::{FamilyName, FontWeightAbsolute},
+    values::{Angle, Url},
+    Multiple,
+};
+use russ_internal::{CssValue, CssWriter, WriteResult, WriteValue};
+use std::io::Write;
+
+/// <https://drafts.csswg.org/css-fonts-4/#font-format-values>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum FontFormat {
+    #[keyword]
+    Collection,
+    #[keyword]
+    EmbeddedOpentype,
+    #[keyword]
+    Opentype,
+    #[keyword]
+    Svg,
+    #[keyword]
+    Truetype,
+    #[keyword]
+    Woff,
+    #[keyword]
+    Woff2,
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#font-tech-values>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum FontTech {
+    #[keyword]
+    FeaturesOpentype,
+    #[keyword]
+    FeaturesAat,
+    #[keyword]
+    FeaturesGraphite,
+    #[keyword(value = "color-COLRv0")]
+    ColorColrV0,
+    #[keyword(value = "color-COLRv1")]
+    ColorColrV1,
+    #[keyword(value = "color-SVG")]
+    ColorSvg,
+    #[keyword(value = "color-sbix")]
+    ColorSbix,
+    #[keyword(value = "color-CBDT")]
+    ColorCbdt,
+    #[keyword]
+    Variations,
+    #[keyword]
+    Palettes,
+    #[keyword]
+    Incremental,
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#src-desc>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum FontFaceSrc {
+    #[value]
+    Url(
+        Url,
+        #[field(option, prefix = "format(", suffix = ")")] Option<FontFormat>,
+        #[field(option, prefix = "tech(", suffix = ")")] Option<Multiple<FontTech>>,
+    ),
+    #[function]
+    Local(FamilyName),
+}
+impl FontFaceSrc {
+    pub fn url(url: impl Into<Url>) -> Self {
+        Self::Url(url.into(), None, None)
+    }
+
+    pub fn url_format(url: impl Into<Url>, format: FontFormat) -> Self {
+        Self::Url(url.into(), Some(format), None)
+    }
+
+    pub fn local(family: impl Into<FamilyName>) -> Self {
+        Self::Local(family.into())
+    }
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#descdef-font-face-font-weight>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum FontFaceWeight {
+    #[keyword]
+    Auto,
+    #[value]
+    Range(FontWeightAbsolute, Option<FontWeightAbsolute>),
+}
+impl FontFaceWeight {
+    pub fn single(weight: impl Into<FontWeightAbsolute>) -> Self {
+        Self::Range(weight.into(), None)
+    }
+
+    pub fn range(min: impl Into<FontWeightAbsolute>, max: impl Into<FontWeightAbsolute>) -> Self {
+        Self::Range(min.into(), Some(max.into()))
+    }
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#descdef-font-face-font-style>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum FontFaceStyle {
+    #[keyword]
+    Auto,
+    #[keyword]
+    Normal,
+    #[keyword]
+    Italic,
+    #[value(write_fn = "Self::write_oblique")]
+    Oblique(Option<(Angle, Option<Angle>)>),
+}
+impl FontFaceStyle {
+    fn write_oblique(f: &mut CssWriter, range: &Option<(Angle, Option<Angle>)>) -> WriteResult {
+        f.write_str("oblique")?;
+        if let Some((min, max)) = range {
+            f.write_char(' ')?;
+            min.write_value(f)?;
+            if let Some(max) = max {
+                f.write_char(' ')?;
+                max.write_value(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// <https://drafts.csswg.org/css-fonts-4/#font-display-desc>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, CssValue)]
+pub enum FontDisplay {
+    #[keyword]
+    Auto,
+    #[keyword]
+    Block,
+    #[keyword]
+    Swap,
+    #[keyword]
+    Fallback,
+    #[keyword]
+    Optional,
+}
+
+/// Inclusive range of unicode code points.
+///
+/// <https://drafts.csswg.org/css-fonts-4/#unicode-range-desc>
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct UnicodeRange {
+    start: u32,
+    end: u32,
+}
+impl UnicodeRange {
+    pub const MAX_CODE_POINT: u32 = 0x10FFFF;
+
+    pub fn single(code_point: u32) -> Option<Self> {
+        Self::range(code_point, code_point)
+    }
+
+    pub fn range(start: u32, end: u32) -> Option<Self> {
+        if start <= end && end <= Self::MAX_CODE_POINT {
+            Some(Self { start, end })
+        } else {
+            None
+        }
+    }
+
+    pub fn start(self) -> u32 {
+        self.start
+    }
+
+    pub fn end(self) -> u32 {
+        self.end
+    }
+}
+impl WriteValue for UnicodeRange {
+    fn write_value(&self, f: &mut CssWriter) -> WriteResult {
+        write!(f, "U+{:X}", self.start)?;
+        if self.end != self.start {
+            write!(f, "-{:X}", self.end)?;
+        }
+        Ok(())
+    }
+}
+
+/// A `@font-face` at-rule.
+///
+/// Use [`StyleManager::track_font_face`] to add it to the document.
+///
+/// [`StyleManager::track_font_face`]: ../struct.StyleManager.html#method.track_font_face
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FontFace {
+    pub family: FamilyName,
+    pub src: Multiple<FontFaceSrc>,
+    pub weight: Option<FontFaceWeight>,
+    pub style: Option<FontFaceStyle>,
+    pub display: Option<FontDisplay>,
+    pub unicode_range: Option<Multiple<UnicodeRange>>,
+}
+impl FontFace {
+    pub fn build(family: impl Into<FamilyName>, src: impl Into<Multiple<FontFaceSrc>>) -> Self {
+        Self {
+            family: family.into(),
+            src: src.into(),
+            weight: None,
+            style: None,
+            display: None,
+            unicode_range: None,
+        }
+    }
+
+    pub fn with_weight(mut self, weight: FontFaceWeight) -> Self {
+        self.weight = Some(weight);
+        self
+    }
+
+    pub fn with_style(mut self, style: FontFaceStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    pub fn with_display(mut self, display: FontDisplay) -> Self {
+        self.display = Some(display);
+        self
+    }
+
+    pub fn with_unicode_range(mut self, unicode_range: impl Into<Multiple<UnicodeRange>>) -> Self {
+        self.unicode_range = Some(unicode_range.into());
+        self
+    }
+
+    pub fn generate_key(&self) -> CssKey {
+        CssKey::new_hash(self)
+    }
+
+    fn write_descriptor(f: &mut CssWriter, name: &str, value: &impl WriteValue) -> WriteResult {
+        f.write_str(name)?;
+        f.write_char(':')?;
+        value.write_value(f)?;
+        f.write_char(';')
+    }
+
+    pub fn write_css(&self, f: &mut CssWriter) -> WriteResult {
+        f.write_str("@font-face{")?;
+        Self::write_descriptor(f, "font-family", &self.family)?;
+        Self::write_descriptor(f, "src", &self.src)?;
+        if let Some(weight) = &self.weight {
+            Self::write_descriptor(f, "font-weight", weight)?;
+        }
+        if let Some(style) = &self.style {
+            Self::write_descriptor(f, "font-style", style)?;
+        }
+        if let Some(display) = &self.display {
+            Self::write_descriptor(f, "font-display", display)?;
+        }
+        if let Some(unicode_range) = &self.unicode_range {
+            Self::write_descriptor(f, "unicode-range", unicode_range)?;
+        }
+        f.write_char('}')
+    }
+}
+
+/// Handle to a font family defined by a [`FontFace`] that has been added to the document.
+/// The font face is removed from the document when all handles are dropped.
+/// Styles using the family should list the font face with [`Styles::with_font_faces`](super::Styles::with_font_faces)
+/// so that it's kept alive for as long as they are.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct FontFaceRef {
+    family: FamilyName,
+    sheet: StyleSheetRef,
+}
+impl FontFaceRef {
+    pub(super) fn new(family: FamilyName, sheet: StyleSheetRef) -> Self {
+        Self { family, sheet }
+    }
+
+    pub fn family(&self) -> &FamilyName {
+        &self.family
+    }
+
+    pub fn sheet(&self) -> &StyleSheetRef {
+        &self.sheet
+    }
+}
+impl From<&FontFaceRef> for FamilyName {
+    fn from(v: &FontFaceRef) -> Self {
+        v.family.clone()
+    }
+}
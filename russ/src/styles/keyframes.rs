@@ -0,0 +1,84 @@
+/// <https://drafts.csswg.org/css-animations-1/#keyframes>
+use super::{CssKey, Declaration, DeclarationBlock};
+use crate::css::{values::Percentage, Multiple};
+use russ_internal::{CssValue, CssWriter, FromVariants, WriteResult, WriteValue};
+use std::io::Write;
+
+/// <https://drafts.csswg.org/css-animations-1/#typedef-keyframe-selector>
+#[derive(Clone, Debug, Eq, Hash, PartialEq, CssValue, FromVariants)]
+pub enum KeyframeSelector {
+    #[keyword]
+    From,
+    #[keyword]
+    To,
+    Percentage(Percentage),
+}
+
+#[derive(Clone, Debug, Hash)]
+pub struct Keyframe {
+    pub selectors: Multiple<KeyframeSelector>,
+    pub block: DeclarationBlock,
+}
+impl Keyframe {
+    pub fn build<D: Into<Declaration>>(
+        selectors: impl Into<Multiple<KeyframeSelector>>,
+        declarations: impl IntoIterator<Item = D>,
+    ) -> Self {
+        Self {
+            selectors: selectors.into(),
+            block: DeclarationBlock::build(declarations),
+        }
+    }
+
+    pub fn write_keyframe(&self, f: &mut CssWriter) -> WriteResult {
+        self.selectors.write_value(f)?;
+        self.block.write_block(f)
+    }
+}
+
+/// Name of a [`Keyframes`] block.
+/// The name is derived from the [`CssKey`] of the keyframes so that it can't clash with others.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct KeyframesName(CssKey);
+impl WriteValue for KeyframesName {
+    fn write_value(&self, f: &mut CssWriter) -> WriteResult {
+        // identifiers must not start with a digit so we can't use the id on its own.
+        write!(f, "kf-{}", self.0.unique_id())
+    }
+}
+
+/// A `@keyframes` at-rule.
+///
+/// Use [`Keyframes::name`] to reference it in a declaration and [`Styles::with_keyframes`] to make sure
+/// it's added to the document together with the rule sets using it.
+///
+/// [`Styles::with_keyframes`]: ../struct.Styles.html#method.with_keyframes
+#[derive(Clone, Debug, Hash)]
+pub struct Keyframes {
+    frames: Vec<Keyframe>,
+}
+impl Keyframes {
+    pub fn build(frames: impl IntoIterator<Item = Keyframe>) -> Self {
+        Self {
+            frames: frames.into_iter().collect(),
+        }
+    }
+
+    pub fn generate_key(&self) -> CssKey {
+        CssKey::new_hash(self)
+    }
+
+    pub fn name(&self) -> KeyframesName {
+        KeyframesName(self.generate_key())
+    }
+
+    pub fn write_css(&self, f: &mut CssWriter) -> WriteResult {
+        f.write_str("@keyframes ")?;
+        self.name().write_value(f)?;
+        f.write_char('{')?;
+        for frame in &self.frames {
+            frame.write_keyframe(f)?;
+        }
+        f.write_char('}')
+    }
+}
@@ -0,0 +1,75 @@
+/// <https://drafts.csswg.org/css-cascade-5/#layering>
+use super::CssKey;
+use crate::css::{values::CustomIdent, Multiple};
+use russ_internal::{CssWriter, WriteResult, WriteValue};
+
+/// Name of a cascade layer.
+/// Nested layers are represented by multiple identifiers which are joined with a `.`.
+///
+/// <https://drafts.csswg.org/css-cascade-5/#typedef-layer-name>
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LayerName(Multiple<CustomIdent>);
+impl LayerName {
+    pub fn build<IT, V>(parts: IT) -> Option<Self>
+    where
+        IT: IntoIterator<Item = V>,
+        V: Into<CustomIdent>,
+    {
+        Multiple::build(parts).map(Self)
+    }
+
+    /// Create the name of a layer nested inside of this one.
+    pub fn nested(&self, name: impl Into<CustomIdent>) -> Self {
+        Self(Multiple::one_and_more(
+            self.0.first().clone(),
+            self.0.rest().iter().cloned().chain(Some(name.into())),
+        ))
+    }
+}
+impl WriteValue for LayerName {
+    fn write_value(&self, f: &mut CssWriter) -> WriteResult {
+        let (first, rest) = self.0.split_first();
+        first.write_value(f)?;
+        for v in rest {
+            f.write_char('.')?;
+            v.write_value(f)?;
+        }
+        Ok(())
+    }
+}
+impl<T> From<T> for LayerName
+where
+    T: Into<CustomIdent>,
+{
+    fn from(v: T) -> Self {
+        Self(Multiple::one(v))
+    }
+}
+
+/// A `@layer` statement declaring the order of cascade layers.
+/// Layers declared first have the lowest priority.
+///
+/// Use [`StyleManager::track_layer_order`] to add it to the document.
+///
+/// [`StyleManager::track_layer_order`]: ../struct.StyleManager.html#method.track_layer_order
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LayerOrder(Multiple<LayerName>);
+impl LayerOrder {
+    pub fn build<IT, V>(layers: IT) -> Option<Self>
+    where
+        IT: IntoIterator<Item = V>,
+        V: Into<LayerName>,
+    {
+        Multiple::build(layers).map(Self)
+    }
+
+    pub fn generate_key(&self) -> CssKey {
+        CssKey::new_hash(self)
+    }
+
+    pub fn write_css(&self, f: &mut CssWriter) -> WriteResult {
+        f.write_str("@layer ")?;
+        self.0.write_value(f)?;
+        f.write_char(';')
+    }
+}
@@ -0,0 +1,450 @@
+mod container;
+mod contrast;
+mod font_face;
+mod keyframes;
+mod layer;
+mod property;
+mod theme;
+
+use super::bindings;
+use crate::css::values::ValueSyntax;
+pub use container::*;
+pub use contrast::*;
+pub use font_face::*;
+pub use keyframes::*;
+pub use layer::*;
+pub use property::*;
+use russ_internal::{CssWriter, WriteDeclaration, WriteResult, WriteValue, WriterOptions};
+use std::{
+    any::Any,
+    borrow::Cow,
+    collections::{hash_map::DefaultHasher, HashMap},
+    fmt::{self, Debug, Display, Formatter},
+    hash::{Hash, Hasher},
+    rc::{Rc, Weak},
+};
+pub use theme::*;
+
+trait DeclarationInner: WriteDeclaration {
+    fn box_clone(&self) -> Box<dyn DeclarationInner>;
+    fn debug_fmt(&self, f: &mut Formatter) -> fmt::Result;
+    fn generate_key(&self) -> CssKey;
+    fn as_any(&self) -> &dyn Any;
+}
+impl Clone for Box<dyn DeclarationInner> {
+    fn clone(&self) -> Self {
+        self.box_clone()
+    }
+}
+impl Debug for Box<dyn DeclarationInner> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.debug_fmt(f)
+    }
+}
+impl Hash for Box<dyn DeclarationInner> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.generate_key().hash(state)
+    }
+}
+
+impl<T> DeclarationInner for T
+where
+    T: 'static + WriteDeclaration + Clone + Debug + Hash,
+{
+    fn box_clone(&self) -> Box<dyn DeclarationInner> {
+        Box::new(self.clone())
+    }
+
+    fn debug_fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        self.fmt(f)
+    }
+
+    fn generate_key(&self) -> CssKey {
+        CssKey::new_hash(self)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[derive(Clone, Debug, Hash)]
+pub struct Declaration {
+    inner: Box<dyn DeclarationInner>,
+    important: bool,
+}
+impl Declaration {
+    /// Mark the declaration as `!important`.
+    pub fn important(decl: impl Into<Self>) -> Self {
+        Self {
+            important: true,
+            ..decl.into()
+        }
+    }
+
+    pub fn is_important(&self) -> bool {
+        self.important
+    }
+
+    /// Get the declared property if it is of type `T`.
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        self.inner.as_any().downcast_ref()
+    }
+
+    pub fn write_declaration(&self, f: &mut CssWriter) -> WriteResult {
+        self.inner.write_declaration(f)?;
+        if self.important {
+            f.write_str("!important")?;
+        }
+        Ok(())
+    }
+}
+impl<T> From<T> for Declaration
+where
+    T: 'static + WriteDeclaration + Clone + Debug + Hash,
+{
+    fn from(v: T) -> Self {
+        Self {
+            inner: Box::new(v),
+            important: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Hash)]
+pub struct DeclarationBlock(Vec<Declaration>);
+impl DeclarationBlock {
+    pub fn build<D: Into<Declaration>>(declarations: impl IntoIterator<Item = D>) -> Self {
+        Self(declarations.into_iter().map(Into::into).collect())
+    }
+
+    pub fn declarations(&self) -> &[Declaration] {
+        &self.0
+    }
+
+    pub fn write_block(&self, f: &mut CssWriter) -> WriteResult {
+        f.write_char('{')?;
+        for decl in &self.0 {
+            decl.write_declaration(f)?;
+            f.write_char(';')?;
+        }
+        f.write_char('}')
+    }
+
+    pub fn write_block_with_selector(&self, f: &mut CssWriter, selector: &str) -> WriteResult {
+        f.write_str(selector)?;
+        self.write_block(f)
+    }
+}
+
+#[derive(Clone, Debug, Hash)]
+pub struct RuleSet {
+    pub block: DeclarationBlock,
+    pub containers: Vec<ContainerRule>,
+}
+impl RuleSet {
+    pub fn build<D: Into<Declaration>>(declarations: impl IntoIterator<Item = D>) -> Self {
+        Self {
+            block: DeclarationBlock::build(declarations),
+            containers: Vec::new(),
+        }
+    }
+
+    /// Add declarations which only apply to the same element while the container query matches.
+    pub fn with_container<D: Into<Declaration>>(
+        mut self,
+        query: ContainerQuery,
+        declarations: impl IntoIterator<Item = D>,
+    ) -> Self {
+        self.containers
+            .push(ContainerRule::build(query, declarations));
+        self
+    }
+
+    pub fn write_rule_set(&self, f: &mut CssWriter, class_id: impl Display) -> WriteResult {
+        let selector = format!(".{}", class_id);
+        self.block.write_block_with_selector(f, &selector)?;
+        for container in &self.containers {
+            container.write_container_rule(f, &selector)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CssKey(u64);
+impl CssKey {
+    pub fn new_hash<T: Hash>(v: T) -> Self {
+        let mut h = DefaultHasher::new();
+        v.hash(&mut h);
+        Self(h.finish())
+    }
+
+    pub fn unique_id(self) -> String {
+        format!("{:x}", self.0)
+    }
+}
+
+#[derive(Clone, Debug, Hash)]
+pub struct Styles {
+    rule_sets: Vec<RuleSet>,
+    keyframes: Vec<Keyframes>,
+    font_faces: Vec<FontFace>,
+    layer: Option<LayerName>,
+}
+impl Styles {
+    pub fn build(rule_sets: impl IntoIterator<Item = RuleSet>) -> Self {
+        Self {
+            rule_sets: rule_sets.into_iter().map(Into::into).collect(),
+            keyframes: Vec::new(),
+            font_faces: Vec::new(),
+            layer: None,
+        }
+    }
+
+    /// Keyframes referenced by the rule sets.
+    /// They are added to the document whenever the styles are and stay there for as long as the styles are in use.
+    pub fn with_keyframes(mut self, keyframes: impl IntoIterator<Item = Keyframes>) -> Self {
+        self.keyframes.extend(keyframes);
+        self
+    }
+
+    /// Font faces whose family is used by the rule sets.
+    /// Like keyframes, they stay in the document for as long as the styles are in use.
+    pub fn with_font_faces(mut self, font_faces: impl IntoIterator<Item = FontFace>) -> Self {
+        self.font_faces.extend(font_faces);
+        self
+    }
+
+    /// Put the rule sets in the given cascade layer.
+    /// Use a [`LayerOrder`] to control how the layers are ordered.
+    pub fn in_layer(mut self, layer: impl Into<LayerName>) -> Self {
+        self.layer = Some(layer.into());
+        self
+    }
+
+    pub fn rule_sets(&self) -> &[RuleSet] {
+        &self.rule_sets
+    }
+
+    pub fn generate_key(&self) -> CssKey {
+        CssKey::new_hash(self)
+    }
+
+    fn write_rule_sets(&self, f: &mut CssWriter, prefix: impl Display) -> WriteResult {
+        for (i, rule_set) in self.rule_sets.iter().enumerate() {
+            rule_set.write_rule_set(f, format!("{}-{}", prefix, i))?;
+        }
+        Ok(())
+    }
+
+    pub fn write_css(&self, f: &mut CssWriter, prefix: impl Display) -> WriteResult {
+        if let Some(layer) = &self.layer {
+            f.write_str("@layer ")?;
+            layer.write_value(f)?;
+            f.write_char('{')?;
+            self.write_rule_sets(f, prefix)?;
+            f.write_char('}')
+        } else {
+            self.write_rule_sets(f, prefix)
+        }
+    }
+}
+
+/// A reference to a style sheet.
+/// When dropped, the style sheet is removed from the document.
+#[derive(Debug, Eq, Hash, PartialEq)]
+pub struct StyleSheet {
+    id: String,
+    dependencies: Vec<StyleSheetRef>,
+}
+impl StyleSheet {
+    fn new(id: Cow<str>) -> Self {
+        Self {
+            id: id.into_owned(),
+            dependencies: Vec::new(),
+        }
+    }
+
+    #[must_use = "style sheet is removed when this is dropped"]
+    pub fn attach(id: Cow<str>, body: &str) -> Option<Self> {
+        if bindings::add_style_sheet(&id, body) {
+            Some(Self::new(id))
+        } else {
+            None
+        }
+    }
+
+    /// Like [`StyleSheet::attach`] but the style sheet is added before all other style sheets.
+    #[must_use = "style sheet is removed when this is dropped"]
+    pub fn attach_first(id: Cow<str>, body: &str) -> Option<Self> {
+        if bindings::add_style_sheet_first(&id, body) {
+            Some(Self::new(id))
+        } else {
+            None
+        }
+    }
+
+    /// Keep the given style sheets alive for as long as this one is.
+    pub fn with_dependencies(mut self, dependencies: Vec<StyleSheetRef>) -> Self {
+        self.dependencies = dependencies;
+        self
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Replace the content of the style sheet.
+    pub fn update(&self, body: &str) {
+        let updated = bindings::update_style_sheet(self.id(), body);
+        debug_assert!(updated, "style sheet was removed from the document");
+    }
+}
+impl Drop for StyleSheet {
+    fn drop(&mut self) {
+        let removed = bindings::remove_style_sheet(self.id());
+        debug_assert!(
+            removed,
+            "style sheet was removed but it still has a reference pointing to it"
+        );
+    }
+}
+
+pub type StyleSheetRef = Rc<StyleSheet>;
+
+fn render_css(
+    options: WriterOptions,
+    write_css: impl FnOnce(&mut CssWriter) -> WriteResult,
+) -> String {
+    let mut body_buf = Vec::new();
+    write_css(&mut CssWriter::with_options(&mut body_buf, options)).expect("failed to render CSS");
+    // SAFETY: CSSWriter should never produce invalid UTF8.
+    //  On the off chance that it does, it will be handled by `TextDecoder` in JavaScript and generate a panic.
+    unsafe { String::from_utf8_unchecked(body_buf) }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct StyleManager {
+    sheets: HashMap<CssKey, Weak<StyleSheet>>,
+    options: WriterOptions,
+}
+impl StyleManager {
+    /// Create a manager which renders all style sheets with the given options.
+    pub fn with_options(options: WriterOptions) -> Self {
+        Self {
+            sheets: HashMap::new(),
+            options,
+        }
+    }
+
+    fn get(&self, key: CssKey) -> Option<StyleSheetRef> {
+        self.sheets.get(&key).and_then(Weak::upgrade)
+    }
+
+    fn track_sheet(&mut self, key: CssKey, sheet_ref: StyleSheet) -> StyleSheetRef {
+        let shared_ref = Rc::new(sheet_ref);
+        self.sheets.insert(key, Rc::downgrade(&shared_ref));
+        shared_ref
+    }
+
+    fn add_sheet_with_key(
+        &mut self,
+        key: CssKey,
+        dependencies: Vec<StyleSheetRef>,
+        write_css: impl FnOnce(&mut CssWriter, &str) -> WriteResult,
+    ) -> StyleSheetRef {
+        let unique_id = key.unique_id();
+        let body = render_css(self.options, |f| write_css(f, &unique_id));
+        let style_sheet = StyleSheet::attach(Cow::from(unique_id), &body)
+            .expect("failed to add style sheet")
+            .with_dependencies(dependencies);
+        self.track_sheet(key, style_sheet)
+    }
+
+    fn add_styles_with_key(&mut self, key: CssKey, styles: &Styles) -> StyleSheetRef {
+        let mut dependencies: Vec<_> = styles
+            .keyframes
+            .iter()
+            .map(|keyframes| self.track_keyframes(keyframes))
+            .collect();
+        dependencies.extend(
+            styles
+                .font_faces
+                .iter()
+                .map(|font_face| self.track_font_face(font_face).sheet().clone()),
+        );
+        self.add_sheet_with_key(key, dependencies, |f, unique_id| {
+            styles.write_css(f, unique_id)
+        })
+    }
+
+    pub fn track_styles_with_key(&mut self, key: CssKey, styles: &Styles) -> StyleSheetRef {
+        self.get(key)
+            .unwrap_or_else(|| self.add_styles_with_key(key, styles))
+    }
+
+    pub fn track_styles(&mut self, styles: &Styles) -> StyleSheetRef {
+        self.track_styles_with_key(styles.generate_key(), styles)
+    }
+
+    /// Declare the order of cascade layers.
+    /// The statement is added before all other style sheets so that the order doesn't depend on when the layers are
+    /// first used.
+    /// Multiple order declarations are added in the order they are tracked in.
+    pub fn track_layer_order(&mut self, order: &LayerOrder) -> StyleSheetRef {
+        let key = order.generate_key();
+        self.get(key).unwrap_or_else(|| {
+            let body = render_css(self.options, |f| order.write_css(f));
+            let style_sheet = StyleSheet::attach_first(Cow::from(key.unique_id()), &body)
+                .expect("failed to add style sheet");
+            self.track_sheet(key, style_sheet)
+        })
+    }
+
+    /// Add the font face to the document.
+    /// The returned handle can be used wherever a font family name is expected.
+    pub fn track_font_face(&mut self, font_face: &FontFace) -> FontFaceRef {
+        let key = font_face.generate_key();
+        let sheet = self.get(key).unwrap_or_else(|| {
+            self.add_sheet_with_key(key, Vec::new(), |f, _| font_face.write_css(f))
+        });
+        FontFaceRef::new(font_face.family.clone(), sheet)
+    }
+
+    /// Register a custom property.
+    /// Registered properties are type checked by the browser and can be animated.
+    pub fn track_property<T>(&mut self, property: &PropertyRule<T>) -> StyleSheetRef
+    where
+        T: ValueSyntax + Hash,
+    {
+        let key = property.generate_key();
+        self.get(key).unwrap_or_else(|| {
+            self.add_sheet_with_key(key, Vec::new(), |f, _| property.write_css(f))
+        })
+    }
+
+    /// Expose the tokens of the theme to all elements in the scope.
+    /// Applying another theme to the same scope replaces the previous one in place, styles referring to the tokens
+    /// don't need to be added again.
+    pub fn apply_theme(
+        &mut self,
+        scope: &ThemeScope,
+        theme: &(impl Theme + ?Sized),
+    ) -> StyleSheetRef {
+        let key = scope.generate_key();
+        if let Some(sheet) = self.get(key) {
+            sheet.update(&render_css(self.options, |f| scope.write_theme(f, theme)));
+            sheet
+        } else {
+            self.add_sheet_with_key(key, Vec::new(), |f, _| scope.write_theme(f, theme))
+        }
+    }
+
+    pub fn track_keyframes(&mut self, keyframes: &Keyframes) -> StyleSheetRef {
+        let key = keyframes.generate_key();
+        self.get(key).unwrap_or_else(|| {
+            self.add_sheet_with_key(key, Vec::new(), |f, _| keyframes.write_css(f))
+        })
+    }
+}
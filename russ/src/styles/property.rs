@@ -0,0 +1,66 @@
+/// <https://drafts.css-houdini.org/css-properties-values-api-1/#at-property-rule>
+use super::CssKey;
+use crate::css::values::{DashedIdent, ValueSyntax, Var};
+use russ_internal::{CssWriter, WriteResult, WriteValue};
+use std::hash::Hash;
+
+/// A `@property` at-rule registering a custom property.
+/// The syntax of the property is derived from the value type `T`.
+///
+/// Use [`StyleManager::track_property`] to add it to the document.
+///
+/// [`StyleManager::track_property`]: ../struct.StyleManager.html#method.track_property
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PropertyRule<T> {
+    pub name: DashedIdent,
+    pub inherits: bool,
+    pub initial_value: T,
+}
+impl<T> PropertyRule<T>
+where
+    T: ValueSyntax,
+{
+    /// Returns `None` if the initial value isn't computationally independent, for example if it uses a `var()` or
+    /// a length relative to the font size.
+    /// Browsers ignore such rules.
+    pub fn build(
+        name: impl Into<DashedIdent>,
+        inherits: bool,
+        initial_value: impl Into<T>,
+    ) -> Option<Self> {
+        let initial_value = initial_value.into();
+        if !initial_value.is_computationally_independent() {
+            return None;
+        }
+
+        Some(Self {
+            name: name.into(),
+            inherits,
+            initial_value,
+        })
+    }
+
+    pub fn var(&self) -> Var<T> {
+        Var::new(self.name.clone())
+    }
+
+    pub fn write_css(&self, f: &mut CssWriter) -> WriteResult {
+        f.write_str("@property ")?;
+        self.name.write_value(f)?;
+        f.write_str("{syntax:\"")?;
+        T::write_syntax(f)?;
+        f.write_str("\";inherits:")?;
+        f.write_str(if self.inherits { "true" } else { "false" })?;
+        f.write_str(";initial-value:")?;
+        self.initial_value.write_value(f)?;
+        f.write_str(";}")
+    }
+}
+impl<T> PropertyRule<T>
+where
+    T: Hash,
+{
+    pub fn generate_key(&self) -> CssKey {
+        CssKey::new_hash(self)
+    }
+}
@@ -0,0 +1,108 @@
+use super::{CssKey, Declaration, DeclarationBlock};
+use crate::css::{
+    props::CustomProperty,
+    values::{CustomIdent, Var},
+};
+use russ_internal::{CssWriter, WriteResult, WriteValue};
+use std::{
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// Typed handle to a custom property defined by a [`Theme`].
+///
+/// Tokens are usually declared as constants so that both the theme and the components using it refer to the same
+/// custom property.
+pub struct ThemeToken<T> {
+    name: &'static str,
+    _value: PhantomData<fn() -> T>,
+}
+impl<T> ThemeToken<T> {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _value: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Reference the token in a declaration.
+    pub fn var(&self) -> Var<T> {
+        Var::new(self.name)
+    }
+
+    /// Define the value of the token.
+    pub fn declare(&self, value: impl Into<T>) -> CustomProperty<T> {
+        CustomProperty::build(self.name, value)
+    }
+}
+impl<T> Clone for ThemeToken<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T> Copy for ThemeToken<T> {}
+impl<T> Debug for ThemeToken<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_tuple("ThemeToken").field(&self.name).finish()
+    }
+}
+impl<T> PartialEq for ThemeToken<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+    }
+}
+impl<T> Eq for ThemeToken<T> {}
+impl<T> Hash for ThemeToken<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state)
+    }
+}
+
+/// Set of design tokens which are exposed to the document as custom properties.
+///
+/// Use [`StyleManager::apply_theme`] to add it to the document.
+///
+/// [`StyleManager::apply_theme`]: ../struct.StyleManager.html#method.apply_theme
+pub trait Theme {
+    /// Declarations of all tokens, usually created using [`ThemeToken::declare`].
+    fn declarations(&self) -> Vec<Declaration>;
+}
+
+/// Elements a [`Theme`] applies to.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ThemeScope {
+    /// The whole document.
+    Root,
+    /// Elements with the given class and their descendants.
+    Class(CustomIdent),
+}
+impl ThemeScope {
+    pub fn class(name: impl Into<CustomIdent>) -> Self {
+        Self::Class(name.into())
+    }
+
+    /// Every scope has exactly one sheet, switching themes replaces its content.
+    pub fn generate_key(&self) -> CssKey {
+        CssKey::new_hash(self)
+    }
+
+    pub fn write_selector(&self, f: &mut CssWriter) -> WriteResult {
+        match self {
+            Self::Root => f.write_str(":root"),
+            Self::Class(name) => {
+                f.write_char('.')?;
+                name.write_value(f)
+            }
+        }
+    }
+
+    pub fn write_theme(&self, f: &mut CssWriter, theme: &(impl Theme + ?Sized)) -> WriteResult {
+        self.write_selector(f)?;
+        DeclarationBlock::build(theme.declarations()).write_block(f)
+    }
+}
@@ -30,3 +30,23 @@ fn test_remove() {
     assert!(remove_style_sheet("remove.2"));
     assert!(!remove_style_sheet("remove.1"));
 }
+
+#[wasm_bindgen_test]
+fn test_add_first() {
+    assert!(add_style_sheet("add_first.1", ""));
+    assert!(add_style_sheet_first("add_first.2", ""));
+    assert!(!add_style_sheet_first("add_first.2", ""));
+    assert!(!add_style_sheet("add_first.2", ""));
+
+    assert!(remove_style_sheet("add_first.1"));
+    assert!(remove_style_sheet("add_first.2"));
+}
+
+#[wasm_bindgen_test]
+fn test_update() {
+    assert!(!update_style_sheet("update.1", "a{width: 1px;}"));
+    assert!(add_style_sheet("update.1", ""));
+    assert!(update_style_sheet("update.1", "a{width: 1px;}"));
+
+    assert!(remove_style_sheet("update.1"));
+}
@@ -1,9 +1,13 @@
-use russ::css::{multiple, values::*, CssWriter, WriteValue};
+use russ::css::{multiple, values::*, CssWriter, WriteValue, WriterOptions};
 
 fn render(value: impl WriteValue) -> String {
+    render_with_options(value, WriterOptions::default())
+}
+
+fn render_with_options(value: impl WriteValue, options: WriterOptions) -> String {
     let mut v = Vec::new();
     value
-        .write_value(&mut CssWriter::new(&mut v))
+        .write_value(&mut CssWriter::with_options(&mut v, options))
         .expect("failed to write value");
     String::from_utf8(v).expect("invalid utf8 returned")
 }
@@ -11,16 +15,297 @@ fn render(value: impl WriteValue) -> String {
 #[test]
 fn calc() {
     assert_eq!(
-        render(Calc::bin_sub(Percentage::from(100), Length::px(80))),
+        render(Calc::<LengthPercentage>::bin_sub(
+            Percentage::from(100),
+            Length::px(80)
+        )),
         "calc(100% - 80px)"
     );
     assert_eq!(
-        render(Calc::bin_div(Calc::bin_div(Length::px(100), 2), 2)),
+        render(Calc::bin_div(
+            Calc::<Length>::bin_div(Length::px(100), 2),
+            2
+        )),
         "calc((100px / 2) / 2)"
     );
     assert_eq!(
         render(Color::rgba(Calc::bin_sub(255, 5), 0, 153, 1)),
-        "rgb(calc(255 - 5),0,153,1)"
+        "rgb(250 0 153 / 1)"
+    );
+}
+
+#[test]
+fn calc_simplification() {
+    assert_eq!(
+        render(Calc::<Length>::bin_sub(Length::px(100), Length::px(20)).simplify()),
+        "calc(80px)"
+    );
+    assert_eq!(
+        render(Length::from(Calc::bin_sub(Length::px(100), Length::px(20)))),
+        "80px"
+    );
+    assert_eq!(
+        render(Length::from(Calc::bin_add(
+            Length::inches(1),
+            Length::pt(6)
+        ))),
+        "104px"
+    );
+    // units are only converted when they have to be combined
+    assert_eq!(render(Length::cm(1) + Length::cm(1)), "2cm");
+    assert_eq!(render(Length::Zero + Length::cm(1)), "1cm");
+    // terms are sorted so that the order they were written in doesn't matter
+    let a = Calc::<LengthPercentage>::bin_add(Length::px(1), Percentage::from(10)).simplify();
+    let b = Calc::<LengthPercentage>::bin_add(Percentage::from(10), Length::px(1)).simplify();
+    assert_eq!(render(a.clone()), "calc(10% + 1px)");
+    assert_eq!(a, b);
+    assert_eq!(
+        render(Length::from(Calc::bin_div(
+            Calc::<Length>::bin_div(Length::px(100), 2),
+            2
+        ))),
+        "25px"
+    );
+    // nested calc expressions are unwrapped and the subtraction is distributed
+    let gutter = Length::from(Calc::bin_add(Length::rem(1), Length::px(2)));
+    assert_eq!(
+        render(LengthPercentage::from(Calc::<LengthPercentage>::bin_sub(
+            Percentage::from(50),
+            gutter
+        ))),
+        "calc(50% - 2px - 1rem)"
+    );
+    assert_eq!(
+        render(LengthPercentage::from(Calc::<LengthPercentage>::bin_add(
+            Calc::bin_mul(Length::px(4), 2),
+            Calc::bin_sub(Percentage::from(10), Length::px(8))
+        ))),
+        "calc(10% + 0px)"
+    );
+    assert_eq!(
+        render(Length::from(Calc::bin_mul(
+            Calc::<Length>::bin_add(Length::from(Var::new("gap")), Length::px(2)),
+            3
+        ))),
+        "calc(6px + var(--gap) * 3)"
+    );
+    assert_eq!(
+        render(Length::from(Calc::bin_mul(
+            Length::px(4),
+            Calc::bin_add(1, Number::from(Var::new("scale")))
+        ))),
+        "calc(4px * (1 + var(--scale)))"
+    );
+    assert_eq!(
+        render(Number::from(Calc::bin_div(Number::from(1), 0))),
+        "calc(1 / 0)"
+    );
+}
+
+#[test]
+fn typed_calc() {
+    let gutter = Length::from(Calc::bin_add(Length::rem(1), Length::px(2)));
+    assert_eq!(render(gutter.clone()), "calc(2px + 1rem)");
+    assert_eq!(
+        render(Calc::<LengthPercentage>::bin_sub(
+            Percentage::from(50),
+            gutter
+        )),
+        "calc(50% - calc(2px + 1rem))"
+    );
+    assert_eq!(
+        render(Calc::<Length>::bin_mul(
+            Length::px(4),
+            Calc::bin_add(1, Number::from(Var::new("scale")))
+        )),
+        "calc(4px * (1 + var(--scale)))"
+    );
+    assert_eq!(
+        render(Angle::from(Calc::bin_div(Angle::turn(1.5), 3))),
+        "0.5turn"
+    );
+    assert_eq!(
+        render(Angle::from(Calc::bin_add(
+            Angle::turn(0.25),
+            Angle::deg(30)
+        ))),
+        "120deg"
+    );
+    assert_eq!(
+        render(Calc::<AnglePercentage>::bin_add(
+            Percentage::from(10),
+            Angle::deg(5)
+        )),
+        "calc(10% + 5deg)"
+    );
+    assert_eq!(
+        render(Time::from(Calc::bin_sub(Time::s(1), Time::ms(200)))),
+        "0.8s"
+    );
+    assert_eq!(
+        render(Resolution::from(Calc::bin_mul(Resolution::x(1), 2))),
+        "2dppx"
+    );
+    assert_eq!(render(Integer::from(Calc::bin_mul(3, 4))), "12");
+}
+
+#[test]
+fn operators() {
+    assert_eq!(
+        render(Percentage::from(100) - Length::px(80)),
+        "calc(100% - 80px)"
+    );
+    assert_eq!(render(Length::px(100) - Length::px(20)), "80px");
+    assert_eq!(render(Length::px(10) + Length::em(1)), "calc(1em + 10px)");
+    assert_eq!(render(Length::px(10) * 2), "20px");
+    assert_eq!(
+        render(Length::from(Var::new("gap")) / 2),
+        "calc(var(--gap) / 2)"
+    );
+    assert_eq!(render(-Length::px(4)), "-4px");
+    assert_eq!(
+        render(-Length::from(Var::new("gap"))),
+        "calc(var(--gap) * -1)"
+    );
+    assert_eq!(render(Angle::deg(90) + Angle::turn(1)), "450deg");
+    assert_eq!(render(Time::s(1) - Time::ms(250)), "0.75s");
+    assert_eq!(render(Number::from(2) * 3 + 1), "7");
+    assert_eq!(render(Percentage::from(50) + Percentage::from(25)), "75%");
+    assert_eq!(render(Percentage::from(10) * 3), "30%");
+    assert_eq!(
+        render(Calc::<Length>::unary(Length::from(Var::new("a"))) + Length::px(2) - Length::em(1)),
+        "calc(var(--a) + 2px - 1em)"
+    );
+    assert_eq!(
+        render(-Calc::<Length>::bin_add(Length::px(2), Length::em(1))),
+        "calc((2px + 1em) * -1)"
+    );
+}
+
+#[test]
+fn math_functions() {
+    assert_eq!(
+        render(Length::from(MathFunction::<Length>::clamp(
+            Length::rem(1),
+            Length::vw(2.5),
+            Length::rem(2)
+        ))),
+        "clamp(1rem,2.5vw,2rem)"
+    );
+    assert_eq!(
+        render(Length::from(
+            MathFunction::<Length>::min(vec![Length::px(10), Length::px(20)]).unwrap()
+        )),
+        "10px"
+    );
+    assert_eq!(
+        render(LengthPercentage::from(
+            MathFunction::<LengthPercentage>::max(vec![
+                LengthPercentage::from(Percentage::from(50)),
+                Length::px(200).into(),
+            ])
+            .unwrap()
+        )),
+        "max(50%,200px)"
+    );
+    assert_eq!(
+        render(Calc::<Length>::bin_add(
+            MathFunction::min(vec![Length::vw(10), Length::px(100)]).unwrap(),
+            Length::px(2)
+        )),
+        "calc(min(10vw,100px) + 2px)"
+    );
+    assert_eq!(
+        render(Length::from(MathFunction::<Length>::round(
+            Some(RoundingStrategy::Up),
+            Length::px(13),
+            Length::px(5)
+        ))),
+        "15px"
+    );
+    assert_eq!(
+        render(Length::from(MathFunction::<Length>::round(
+            Some(RoundingStrategy::ToZero),
+            Length::from(Var::new("x")),
+            Length::px(5)
+        ))),
+        "round(to-zero,var(--x),5px)"
+    );
+    assert_eq!(render(Number::from(MathFunction::modulo(-7, 3))), "2");
+    assert_eq!(render(Number::from(MathFunction::rem(-7, 3))), "-1");
+    assert_eq!(
+        render(Length::from(MathFunction::<Length>::abs(Length::px(-4)))),
+        "4px"
+    );
+    assert_eq!(
+        render(Length::from(
+            MathFunction::<Length>::hypot(vec![Length::px(3), Length::px(4)]).unwrap()
+        )),
+        "5px"
+    );
+    assert_eq!(
+        render(Integer::from(MathFunction::max(vec![1, 3]).unwrap())),
+        "3"
+    );
+}
+
+#[test]
+fn number_functions() {
+    assert_eq!(render(Number::from(NumberFunction::pow(2, 10))), "1024");
+    assert_eq!(
+        render(Number::from(NumberFunction::sqrt(Number::from(Var::new(
+            "area"
+        ))))),
+        "sqrt(var(--area))"
+    );
+    assert_eq!(
+        render(Number::from(NumberFunction::sin(Angle::deg(90)))),
+        "1"
+    );
+    assert_eq!(
+        render(Number::from(NumberFunction::sign(Length::px(-3)))),
+        "-1"
+    );
+    assert_eq!(
+        render(Number::from(NumberFunction::log(8, Some(2.into())))),
+        "3"
+    );
+    assert_eq!(
+        render(Angle::from(AngleFunction::atan2(
+            Length::px(1),
+            Length::px(1)
+        ))),
+        "45deg"
+    );
+    assert_eq!(
+        render(Angle::from(AngleFunction::atan2(
+            Time::s(1),
+            Time::from(Var::new("duration"))
+        ))),
+        "atan2(1s,var(--duration))"
+    );
+    assert_eq!(render(Number::from(NumberFunction::round(None, 2.5))), "3");
+    assert_eq!(
+        render(Number::from(NumberFunction::round(
+            Some(RoundingStrategy::Down),
+            Number::from(Var::new("n"))
+        ))),
+        "round(down,var(--n))"
+    );
+    assert_eq!(
+        render(Number::from(NumberFunction::from(MathConstant::Infinity))),
+        "calc(infinity)"
+    );
+    assert_eq!(
+        render(Calc::<Length>::bin_mul(Length::px(10), MathConstant::Pi)),
+        "calc(10px * pi)"
+    );
+    assert_eq!(
+        render(Length::from(Calc::bin_mul(
+            Length::px(10),
+            NumberFunction::sign(Number::from(Var::new("direction")))
+        ))),
+        "calc(10px * sign(var(--direction)))"
     );
 }
 
@@ -28,16 +313,25 @@ fn calc() {
 fn color() {
     assert_eq!(render(Color::hex(0xff0099)), "#FF0099");
 
-    assert_eq!(render(Color::rgb(255, 0, 153)), "rgb(255,0,153)");
+    assert_eq!(render(Color::rgb(255, 0, 153)), "rgb(255 0 153)");
     assert_eq!(
         render(Color::rgb(
             Percentage::from(100),
             Percentage::from(0),
             Percentage::from(60)
         )),
-        "rgb(100%,0%,60%)"
+        "rgb(100% 0% 60%)"
+    );
+    assert_eq!(render(Color::rgba(255, 0, 153, 1)), "rgb(255 0 153 / 1)");
+    assert_eq!(
+        render(Color::Rgb {
+            r: 255.into(),
+            g: 0.into(),
+            b: 153.into(),
+            a: Some(Percentage::from(50).into()),
+        }),
+        "rgb(255 0 153 / 50%)"
     );
-    assert_eq!(render(Color::rgba(255, 0, 153, 1)), "rgb(255,0,153,1)");
 
     assert_eq!(
         render(Color::hsl(
@@ -45,7 +339,7 @@ fn color() {
             Percentage::from(60),
             Percentage::from(70),
         )),
-        "hsl(0.75turn,60%,70%)"
+        "hsl(0.75turn 60% 70%)"
     );
     assert_eq!(
         render(Color::hsla(
@@ -54,8 +348,358 @@ fn color() {
             Percentage::from(50),
             Percentage::from(15),
         )),
-        "hsl(270deg,60%,50%,15%)"
+        "hsl(270deg 60% 50% / 15%)"
+    );
+
+    assert_eq!(
+        render(Color::hwb(
+            Angle::deg(194),
+            Percentage::from(0),
+            Percentage::from(0)
+        )),
+        "hwb(194deg 0% 0%)"
+    );
+    assert_eq!(
+        render(Color::lab(Percentage::from(29.2345), 39.3825, 20.0664)),
+        "lab(29.2345% 39.3825 20.0664)"
+    );
+    assert_eq!(
+        render(Color::lch(
+            Percentage::from(52.2345),
+            72.2,
+            Angle::deg(56.2)
+        )),
+        "lch(52.2345% 72.2 56.2deg)"
+    );
+    assert_eq!(
+        render(Color::oklab(0.40101, 0.1147, 0.0453)),
+        "oklab(0.40101 0.1147 0.0453)"
+    );
+    assert_eq!(
+        render(Color::Oklch {
+            l: Percentage::from(60).into(),
+            c: 0.15.into(),
+            h: Angle::deg(50),
+            alpha: Some(Percentage::from(50).into()),
+        }),
+        "oklch(60% 0.15 50deg / 50%)"
+    );
+    assert_eq!(
+        render(Color::display_p3(1, 0.5, 0)),
+        "color(display-p3 1 0.5 0)"
+    );
+    assert_eq!(
+        render(Color::Predefined {
+            space: PredefinedColorSpace::XyzD50,
+            c1: 0.2.into(),
+            c2: 0.3.into(),
+            c3: 0.4.into(),
+            alpha: Some(0.5.into()),
+        }),
+        "color(xyz-d50 0.2 0.3 0.4 / 0.5)"
+    );
+}
+
+#[test]
+fn hex_color() {
+    assert_eq!(render(HexColor::rgba(0xff009980)), "#FF009980");
+    assert_eq!(render(HexColor::rgba(0xff0099ff)), "#FF0099");
+    assert_eq!(render(HexColor::rgb(0xff0099).shortest()), "#F09");
+    assert_eq!(render(HexColor::rgba(0xff009988).shortest()), "#F098");
+    // can't be shortened
+    assert_eq!(render(HexColor::rgb(0xff0098).shortest()), "#FF0098");
+
+    assert_eq!(
+        HexColor::parse("#0f08"),
+        Some(HexColor::rgba(0x00ff0088).shortest())
+    );
+    assert_eq!(render(HexColor::parse("#0f08").unwrap()), "#0F08");
+    assert_eq!(HexColor::parse("ff0099"), Some(HexColor::rgb(0xff0099)));
+    assert_eq!(HexColor::parse("#Ff0099"), HexColor::parse("#fF0099"));
+    assert_eq!(
+        HexColor::parse("#ff00"),
+        Some(HexColor::rgba(0xffff0000).shortest())
+    );
+    assert_eq!(HexColor::parse("#ff009"), None);
+    assert_eq!(HexColor::parse("#gg0099"), None);
+    assert_eq!(HexColor::parse("#+f0099"), None);
+
+    assert_eq!(
+        Color::from(HexColor::rgba(0xff000080))
+            .resolve()
+            .map(|c| c.alpha),
+        Some(128.0 / 255.0)
+    );
+}
+
+#[test]
+fn named_color() {
+    assert_eq!(NamedColor::ALL.len(), 148);
+    assert_eq!(
+        render(Color::from(NamedColor::RebeccaPurple)),
+        "rebeccapurple"
+    );
+    assert_eq!(NamedColor::RebeccaPurple.to_hex(), 0x663399);
+    assert_eq!(NamedColor::Tomato.to_rgb(), [255, 99, 71]);
+    assert_eq!(
+        NamedColor::from_name("LightGoldenrodYellow"),
+        Some(NamedColor::LightGoldenrodYellow)
+    );
+    assert_eq!(NamedColor::from_name("russ"), None);
+}
+
+#[test]
+fn color_conversion() {
+    let red = Color::hex(0xff0000);
+    assert_eq!(
+        render(red.to_space(ColorSpace::Hsl).unwrap()),
+        "hsl(0deg 100% 50%)"
+    );
+    assert_eq!(
+        render(red.to_space(ColorSpace::Lab).unwrap()),
+        "lab(54.29054 80.80492 69.89099)"
+    );
+    assert_eq!(
+        render(red.to_space(ColorSpace::Oklch).unwrap()),
+        "oklch(0.62796 0.25768 29.23388deg)"
+    );
+    assert_eq!(
+        render(
+            Color::from(NamedColor::White)
+                .to_space(ColorSpace::Lch)
+                .unwrap()
+        ),
+        "lch(100 0 0deg)"
+    );
+
+    let purple = Color::from(NamedColor::RebeccaPurple);
+    assert_eq!(
+        render(purple.to_space(ColorSpace::Hwb).unwrap()),
+        "hwb(270deg 20% 40%)"
+    );
+    for &space in &[ColorSpace::Hsl, ColorSpace::Lch, ColorSpace::Oklab] {
+        assert_eq!(purple.to_space(space).unwrap().to_hex(), Some(0x663399));
+    }
+
+    assert_eq!(
+        Color::hsl(Angle::deg(120), Percentage::from(100), Percentage::from(25)).to_hex(),
+        Some(0x008000)
+    );
+    assert_eq!(
+        Color::rgb(Percentage::from(100), 0, 0).to_hex(),
+        Some(0xFF0000)
+    );
+    assert_eq!(Color::CurrentColor.resolve(), None);
+    assert_eq!(Color::display_p3(1, 0, 0).resolve(), None);
+}
+
+#[test]
+fn color_adjustment() {
+    let base = Color::hex(0x336699);
+    assert_eq!(render(base.lighten(0.1).unwrap()), "#4080BF");
+    assert_eq!(render(base.darken(0.1).unwrap()), "#264D73");
+    assert_eq!(render(base.saturate(0.2).unwrap()), "#1F66AD");
+    assert_eq!(render(base.desaturate(1.0).unwrap()), "#666666");
+    assert_eq!(render(base.with_alpha(0.5).unwrap()), "#33669980");
+    assert_eq!(
+        render(Color::rgb(51, 102, 153).with_alpha(0.5).unwrap()),
+        "rgb(51 102 153 / 0.5)"
+    );
+    assert_eq!(
+        render(
+            Color::hsl(Angle::deg(210), Percentage::from(50), Percentage::from(40))
+                .lighten(0.2)
+                .unwrap()
+        ),
+        "hsl(210deg 50% 60%)"
+    );
+
+    let white = Color::from(NamedColor::White);
+    let black = Color::from(NamedColor::Black);
+    assert_eq!(
+        render(white.mix(&black, 0.5, RectangularColorSpace::Srgb).unwrap()),
+        "rgb(127.5 127.5 127.5)"
+    );
+    assert_eq!(
+        render(
+            white
+                .mix(&black, 0.25, RectangularColorSpace::Oklab)
+                .unwrap()
+        ),
+        "oklab(0.75 0 0)"
+    );
+
+    let red = Color::hex(0xff0000);
+    let blue = Color::hex(0x0000ff);
+    assert_eq!(
+        render(red.mix(&blue, 0.5, PolarColorSpace::Hsl).unwrap()),
+        "hsl(300deg 100% 50%)"
+    );
+    assert_eq!(
+        render(
+            red.mix(
+                &blue,
+                0.5,
+                ColorInterpolationMethod::polar(
+                    PolarColorSpace::Hsl,
+                    HueInterpolationMethod::Longer
+                )
+            )
+            .unwrap()
+        ),
+        "hsl(120deg 100% 50%)"
+    );
+    // the powerless hue of white doesn't affect the result
+    assert_eq!(
+        render(red.mix(&white, 0.5, PolarColorSpace::Hsl).unwrap()),
+        "hsl(0deg 50% 75%)"
+    );
+    // interpolation uses premultiplied alpha
+    assert_eq!(
+        render(
+            red.mix(&Color::Transparent, 0.5, RectangularColorSpace::Srgb)
+                .unwrap()
+        ),
+        "rgb(255 0 0 / 0.5)"
+    );
+    assert_eq!(red.mix(&blue, 0.5, RectangularColorSpace::DisplayP3), None);
+}
+
+#[test]
+fn system_color() {
+    assert_eq!(render(Color::from(SystemColor::Canvas)), "Canvas");
+    assert_eq!(render(Color::from(SystemColor::ButtonText)), "ButtonText");
+}
+
+#[test]
+fn color_contrast() {
+    let white = Color::from(NamedColor::White);
+    let black = Color::from(NamedColor::Black);
+    assert_eq!(white.relative_luminance(), Some(1.0));
+    assert_eq!(black.relative_luminance(), Some(0.0));
+    assert!((contrast_ratio(&black, &white).unwrap() - 21.0).abs() < 1e-9);
+    assert_eq!(
+        contrast_ratio(&black, &white),
+        contrast_ratio(&white, &black)
+    );
+    assert!((contrast_ratio(&Color::hex(0x767676), &white).unwrap() - 4.54).abs() < 0.01);
+
+    // translucent foregrounds are composited over the background
+    assert_eq!(
+        contrast_ratio(&Color::rgba(0, 0, 0, 0.5), &white),
+        contrast_ratio(&Color::rgb(127.5, 127.5, 127.5), &white)
+    );
+    assert_eq!(contrast_ratio(&Color::CurrentColor, &white), None);
+}
+
+#[test]
+fn color_mix() {
+    let brand = Color::from(Var::<Color>::new("brand"));
+    let mix = ColorMix::build(
+        RectangularColorSpace::Oklab,
+        ColorMixComponent::new(brand.clone(), 30),
+        Color::from(NamedColor::White),
+    );
+    assert_eq!(
+        render(Color::from(mix.clone())),
+        "color-mix(in oklab,var(--brand) 30%,white)"
+    );
+    // can't be computed at build time
+    assert_eq!(mix.resolve(), None);
+
+    let mix = ColorMix::build(
+        ColorInterpolationMethod::polar(PolarColorSpace::Hsl, HueInterpolationMethod::Longer),
+        Color::hex(0xff0000),
+        Color::hex(0x0000ff),
+    );
+    assert_eq!(
+        render(mix.clone()),
+        "color-mix(in hsl longer hue,#FF0000,#0000FF)"
+    );
+    assert_eq!(
+        render(Color::from(mix.resolve().unwrap())),
+        "hsl(120deg 100% 50%)"
+    );
+
+    // percentages adding up to less than 100% reduce the alpha
+    let mix = ColorMix::build(
+        RectangularColorSpace::Srgb,
+        ColorMixComponent::new(Color::hex(0xff0000), 20),
+        ColorMixComponent::new(Color::hex(0x0000ff), 20),
+    );
+    assert_eq!(
+        render(Color::from(mix.resolve().unwrap())),
+        "rgb(127.5 0 127.5 / 0.4)"
+    );
+}
+
+#[test]
+fn relative_color() {
+    let brand = Var::<Color>::new("brand");
+    assert_eq!(
+        render(Color::from(
+            RelativeColor::build(RelativeColorFunction::Rgb, brand.clone())
+                .with_alpha(Percentage::from(50))
+                .unwrap()
+        )),
+        "rgb(from var(--brand) r g b / 50%)"
+    );
+    assert_eq!(
+        render(
+            RelativeColor::build(RelativeColorFunction::Oklch, brand.clone())
+                .with_channels(0.7, ChannelKeyword::C, ChannelKeyword::H)
+                .unwrap()
+        ),
+        "oklch(from var(--brand) 0.7 c h)"
     );
+    assert_eq!(
+        render(
+            RelativeColor::build(
+                RelativeColorFunction::Predefined(PredefinedColorSpace::XyzD65),
+                Color::hex(0x336699)
+            )
+            .with_alpha(ChannelKeyword::Alpha)
+            .unwrap()
+        ),
+        "color(from #336699 xyz-d65 x y z / alpha)"
+    );
+    assert_eq!(
+        Color::from(RelativeColor::build(
+            RelativeColorFunction::Hsl,
+            Color::hex(0)
+        ))
+        .resolve(),
+        None
+    );
+
+    // angles are only accepted for the hue
+    assert_eq!(
+        render(
+            RelativeColor::build(RelativeColorFunction::Hsl, brand.clone())
+                .with_channels(Angle::turn(0.5), ChannelKeyword::S, ChannelKeyword::L)
+                .unwrap()
+        ),
+        "hsl(from var(--brand) 0.5turn s l)"
+    );
+    assert!(
+        RelativeColor::build(RelativeColorFunction::Oklch, brand.clone())
+            .with_channels(Angle::deg(90), ChannelKeyword::C, ChannelKeyword::H)
+            .is_none()
+    );
+    assert!(
+        RelativeColor::build(RelativeColorFunction::Rgb, brand.clone())
+            .with_alpha(Angle::deg(90))
+            .is_none()
+    );
+    // keywords have to belong to the function
+    assert!(
+        RelativeColor::build(RelativeColorFunction::Rgb, brand.clone())
+            .with_channels(ChannelKeyword::H, ChannelKeyword::S, ChannelKeyword::L)
+            .is_none()
+    );
+    assert!(RelativeColor::build(RelativeColorFunction::Rgb, brand)
+        .with_channels(ChannelKeyword::B, ChannelKeyword::G, ChannelKeyword::Alpha)
+        .is_some());
 }
 
 #[test]
@@ -64,7 +708,7 @@ fn gradient() {
 
     assert_eq!(
         render(Gradient::linear(
-            Some(Angle::deg(45)),
+            Some(Angle::deg(45).into()),
             vec![(Color::hex(0xff0000), Length::Zero, Percentage::from(50))],
             (
                 Color::hex(0x0000ff),
@@ -76,7 +720,7 @@ fn gradient() {
     );
     assert_eq!(
         render(Gradient::linear(
-            Some(Angle::turn(0.25)),
+            Some(Angle::turn(0.25).into()),
             vec![LinearColorStopHint::hint(
                 Color::hex(0xff0000),
                 Percentage::from(10)
@@ -100,6 +744,22 @@ fn gradient() {
         )),
         "linear-gradient(#FF0000 0%,#FFA500 10%,#FFA500 30%,#FFFF00 50%,#FFFF00 70%,#00FF00 90%,#00FF00 100%)"
     );
+    assert_eq!(
+        render(Gradient::linear(
+            Some(SideOrCorner::Left.into()),
+            vec![Color::hex(0x333333)],
+            Color::hex(0xEEEEEE),
+        )),
+        "linear-gradient(to left,#333333,#EEEEEE)"
+    );
+    assert_eq!(
+        render(Gradient::linear(
+            Some(SideOrCorner::BottomRight.into()),
+            vec![Color::hex(0x333333)],
+            Color::hex(0xEEEEEE),
+        )),
+        "linear-gradient(to bottom right,#333333,#EEEEEE)"
+    );
 
     // radial
 
@@ -158,6 +818,107 @@ fn gradient() {
         )),
         "conic-gradient(from 0 at center,#FF0066,#FFDF00)"
     );
+
+    // repeating
+
+    let repeating = Gradient::linear(
+        Some(SideOrCorner::TopLeft.into()),
+        vec![(Color::hex(0xFF0000), Length::Zero, Length::px(10))],
+        (Color::hex(0x0000FF), Length::px(10), Length::px(20)),
+    )
+    .repeating();
+    assert!(repeating.is_repeating());
+    assert_eq!(
+        render(repeating),
+        "repeating-linear-gradient(to top left,#FF0000 0 10px,#0000FF 10px 20px)"
+    );
+    assert_eq!(
+        render(
+            Gradient::radial(
+                Some(GradientShapeSize::ClosestSide),
+                None,
+                vec![Color::hex(0x3F87A6)],
+                (Color::hex(0xEBF8E1), Percentage::from(20)),
+            )
+            .repeating()
+        ),
+        "repeating-radial-gradient(closest-side,#3F87A6,#EBF8E1 20%)"
+    );
+    assert_eq!(
+        render(
+            Gradient::conic(
+                None,
+                None,
+                vec![Color::hex(0xFFFFFF)],
+                (Color::hex(0x000000), Angle::deg(45)),
+            )
+            .repeating()
+        ),
+        "repeating-conic-gradient(#FFFFFF,#000000 45deg)"
+    );
+    assert!(
+        !Gradient::conic(None, None, vec![Color::hex(0xFFFFFF)], Color::hex(0x000000))
+            .is_repeating()
+    );
+
+    // interpolation
+
+    assert_eq!(
+        render(
+            Gradient::linear(None, vec![Color::hex(0xFF0000)], Color::hex(0x0000FF))
+                .interpolate(RectangularColorSpace::Oklab)
+        ),
+        "linear-gradient(in oklab,#FF0000,#0000FF)"
+    );
+    assert_eq!(
+        render(
+            Gradient::linear(
+                Some(Angle::deg(90).into()),
+                vec![Color::hex(0xFF0000)],
+                Color::hex(0x0000FF)
+            )
+            .interpolate(ColorInterpolationMethod::polar(
+                PolarColorSpace::Oklch,
+                HueInterpolationMethod::Longer
+            ))
+        ),
+        "linear-gradient(90deg in oklch longer hue,#FF0000,#0000FF)"
+    );
+    assert_eq!(
+        render(
+            Gradient::radial_ellipse(
+                None,
+                Some(Position::center()),
+                vec![Color::hex(0xFF0000)],
+                Color::hex(0x0000FF)
+            )
+            .interpolate(PolarColorSpace::Hsl)
+        ),
+        "radial-gradient(ellipse at center in hsl,#FF0000,#0000FF)"
+    );
+    assert_eq!(
+        render(
+            Gradient::radial(None, None, vec![Color::hex(0xFF0000)], Color::hex(0x0000FF))
+                .interpolate(RectangularColorSpace::DisplayP3)
+                .repeating()
+        ),
+        "repeating-radial-gradient(in display-p3,#FF0000,#0000FF)"
+    );
+    assert_eq!(
+        render(
+            Gradient::conic(
+                Some(Angle::deg(90)),
+                Some(Position::center()),
+                vec![Color::hex(0xFF0000)],
+                Color::hex(0x0000FF)
+            )
+            .interpolate(ColorInterpolationMethod::polar(
+                PolarColorSpace::Hwb,
+                HueInterpolationMethod::Increasing
+            ))
+        ),
+        "conic-gradient(from 90deg at center in hwb increasing hue,#FF0000,#0000FF)"
+    );
 }
 
 #[test]
@@ -214,6 +975,42 @@ fn number() {
     assert_eq!(render(Number::from(5.5)), "5.5");
 }
 
+#[test]
+fn number_serialization() {
+    assert_eq!(render(Number::from(0.1 + 0.2)), "0.3");
+    assert_eq!(render(Number::from(1.0 / 3.0)), "0.333333");
+    assert_eq!(render(Number::from(-0.0)), "0");
+    assert_eq!(render(Number::from(-0.0000001)), "0");
+    assert_eq!(render(Number::from(1e21)), "1e21");
+    assert_eq!(render(Number::from(-1.5e22)), "-1.5e22");
+    assert_eq!(render(Number::from(f64::INFINITY)), "calc(infinity)");
+    assert_eq!(render(Number::from(f64::NEG_INFINITY)), "calc(-infinity)");
+    assert_eq!(render(Number::from(f64::NAN)), "calc(NaN)");
+    assert_eq!(render(Length::px(f64::INFINITY)), "calc(infinity * 1px)");
+    assert_eq!(
+        render(Length::px(Number::from(Var::new("size")))),
+        "calc(var(--size) * 1px)"
+    );
+
+    let minified = WriterOptions {
+        minify: true,
+        ..WriterOptions::default()
+    };
+    assert_eq!(render_with_options(Length::em(0.5), minified), ".5em");
+    assert_eq!(render_with_options(Number::from(-0.25), minified), "-.25");
+    assert_eq!(render_with_options(Number::from(10.5), minified), "10.5");
+    assert_eq!(
+        render_with_options(
+            Number::from(1.0 / 3.0),
+            WriterOptions {
+                precision: 2,
+                ..minified
+            }
+        ),
+        ".33"
+    );
+}
+
 #[test]
 fn position() {
     assert_eq!(render(Position::center()), "center");
@@ -254,6 +1051,62 @@ fn position() {
 #[test]
 fn ratio() {
     assert_eq!(render(Ratio(16.into(), 9.into())), "16/9");
+
+    // ratios compare in lowest terms
+    assert_eq!(Ratio::from((16, 4)), Ratio::from((4, 1)));
+    assert_eq!(render(Ratio::from((16, 4)).reduced()), "4/1");
+    assert_ne!(Ratio::from((16, 9)), Ratio::from((4, 3)));
+    assert!(Ratio::from((16, 9)) > Ratio::from((4, 3)));
+}
+
+#[test]
+fn canonical_equality() {
+    use std::collections::HashSet;
+
+    // values that render identically are equal
+    assert_eq!(Number::from(-0.0), Number::from(0.0));
+    assert_eq!(Number::from(f64::NAN), Number::from(-f64::NAN));
+    assert_eq!(Length::px(-0.0), Length::px(0.0));
+    assert_eq!(Number::from(0.1 + 0.2), Number::from(0.3));
+    assert_eq!(Number::from(-0.0000001), Number::from(0.0));
+    assert_ne!(Number::from(0.1), Number::from(0.100001));
+    assert_eq!(Number::from(1.0000001e21), Number::from(1e21));
+    assert_ne!(Number::from(1.000001e21), Number::from(1e21));
+    assert_eq!(
+        Number::from(f64::NAN).partial_cmp(&Number::from(f64::NAN)),
+        Some(std::cmp::Ordering::Equal)
+    );
+    assert!(Number::from(0.1) < Number::from(0.100001));
+
+    let set: HashSet<_> = vec![
+        Number::from(0.0),
+        Number::from(-0.0),
+        Number::from(-0.0000001),
+        Number::from(f64::NAN),
+        Number::from(f64::NAN),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 2);
+
+    let set: HashSet<_> = vec![
+        Ratio::from((16, 4)),
+        Ratio::from((4, 1)),
+        Ratio::from((8, 2)),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(set.len(), 1);
+
+    // different units only compare equal semantically
+    assert_ne!(Length::inches(1), Length::px(96));
+    assert!(Length::inches(1).is_equivalent(&Length::px(96)));
+    assert!(Length::cm(2.54).is_equivalent(&Length::mm(25.4)));
+    assert!(Length::Zero.is_equivalent(&Length::px(0)));
+    assert!(!Length::em(1).is_equivalent(&Length::px(16)));
+    assert!(Time::ms(1500).is_equivalent(&Time::s(1.5)));
+    assert!(Angle::deg(180).is_equivalent(&Angle::turn(0.5)));
+    assert!(Resolution::dpi(96).is_equivalent(&Resolution::dppx(1)));
 }
 
 #[test]
@@ -266,4 +1119,95 @@ fn string() {
 fn length() {
     assert_eq!(render(Length::Px(10.into())), "10px");
     assert_eq!(render(Length::Zero), "0");
+    // constructors with a custom name
+    assert_eq!(render(Length::inches(1)), "1in");
+}
+
+#[test]
+fn var() {
+    assert_eq!(render(Var::<Color>::new("brand")), "var(--brand)");
+    assert_eq!(
+        render(Color::from(
+            Var::new("--brand").with_fallback(Color::hex(0x336699))
+        )),
+        "var(--brand,#336699)"
+    );
+    assert_eq!(
+        render(LengthPercentage::from(Var::<Length>::new("gap"))),
+        "var(--gap)"
+    );
+    assert_eq!(
+        render(Calc::<Length>::bin_mul(Length::from(Var::new("gap")), 2)),
+        "calc(var(--gap) * 2)"
+    );
+    assert_eq!(
+        render(Var::<Length>::new("a").with_fallback(Var::new("b").with_fallback(Length::px(4)))),
+        "var(--a,var(--b,4px))"
+    );
+    assert_eq!(render(Number::from(Var::new("scale"))), "var(--scale)");
+}
+
+#[test]
+fn transform_function() {
+    assert_eq!(
+        render(TransformFunction::matrix(1, 0, 0, 1, 10, 20)),
+        "matrix(1,0,0,1,10,20)"
+    );
+    let mut identity: [Number; 16] = Default::default();
+    for i in 0..4 {
+        identity[i * 5] = 1.into();
+    }
+    assert_eq!(
+        render(TransformFunction::matrix3d(identity)),
+        "matrix3d(1,0,0,0,0,1,0,0,0,0,1,0,0,0,0,1)"
+    );
+    assert_eq!(
+        render(TransformFunction::perspective(Length::px(500))),
+        "perspective(500px)"
+    );
+    assert_eq!(
+        render(TransformFunction::rotate(Angle::deg(45))),
+        "rotate(45deg)"
+    );
+    assert_eq!(
+        render(TransformFunction::rotate3d(1, 0, 0, Angle::turn(0.5))),
+        "rotate3d(1,0,0,0.5turn)"
+    );
+    assert_eq!(
+        render(TransformFunction::rotate_x(Angle::rad(1))),
+        "rotateX(1rad)"
+    );
+    assert_eq!(render(TransformFunction::scale(2, None)), "scale(2)");
+    assert_eq!(
+        render(TransformFunction::scale(2, Some(0.5.into()))),
+        "scale(2,0.5)"
+    );
+    assert_eq!(render(TransformFunction::scale_z(3)), "scaleZ(3)");
+    assert_eq!(
+        render(TransformFunction::skew(
+            Angle::deg(10),
+            Some(Angle::deg(20))
+        )),
+        "skew(10deg,20deg)"
+    );
+    assert_eq!(
+        render(TransformFunction::skew_y(Angle::deg(10))),
+        "skewY(10deg)"
+    );
+    assert_eq!(
+        render(TransformFunction::translate(Percentage::from(50), None)),
+        "translate(50%)"
+    );
+    assert_eq!(
+        render(TransformFunction::translate3d(
+            Length::px(1),
+            Percentage::from(2),
+            Length::em(3)
+        )),
+        "translate3d(1px,2%,3em)"
+    );
+    assert_eq!(
+        render(TransformFunction::translate_z(Length::px(10))),
+        "translateZ(10px)"
+    );
 }
@@ -1,7 +1,8 @@
 use russ::{
     bindings,
-    css::{props::*, values::*},
-    RuleSet, StyleManager, Styles,
+    css::{props::*, values::*, WriterOptions},
+    Declaration, FontFace, FontFaceSrc, Keyframe, KeyframeSelector, Keyframes, LayerName,
+    LayerOrder, PropertyRule, RuleSet, StyleManager, Styles, Theme, ThemeScope, ThemeToken,
 };
 use std::rc::Rc;
 use wasm_bindgen_test::*;
@@ -32,3 +33,157 @@ fn tracking() {
     assert!(bindings::has_style_sheet(&id));
     drop(sheet_ref);
 }
+
+#[wasm_bindgen_test]
+fn tracking_keyframes() {
+    let mut manager = StyleManager::default();
+    let keyframes = Keyframes::build(vec![Keyframe::build(
+        KeyframeSelector::To,
+        vec![BackgroundColor(Color::hex(0x000000))],
+    )]);
+    let styles = Styles::build(vec![RuleSet::build(vec![AnimationName(
+        keyframes.name().into(),
+    )])])
+    .with_keyframes(vec![keyframes.clone()]);
+
+    let sheet_ref = manager.track_styles(&styles);
+    let keyframes_id = keyframes.generate_key().unique_id();
+    assert!(bindings::has_style_sheet(&keyframes_id));
+
+    // keyframes are shared with the styles using them
+    let keyframes_ref = manager.track_keyframes(&keyframes);
+    drop(sheet_ref);
+    assert!(bindings::has_style_sheet(&keyframes_id));
+    drop(keyframes_ref);
+    assert!(!bindings::has_style_sheet(&keyframes_id));
+}
+
+#[wasm_bindgen_test]
+fn tracking_font_face() {
+    let mut manager = StyleManager::default();
+    let font_face = FontFace::build("Inter", FontFaceSrc::url("/fonts/inter.woff2"));
+    let font_ref = manager.track_font_face(&font_face);
+    assert_eq!(font_ref.family(), &FamilyName::from("Inter"));
+    // the font face is only injected once
+    let other_ref = manager.track_font_face(&font_face);
+    assert!(Rc::ptr_eq(font_ref.sheet(), other_ref.sheet()));
+
+    let styles = Styles::build(vec![RuleSet::build(vec![FontFamily(
+        FontFamilyItem::from(&font_ref).into(),
+    )])])
+    .with_font_faces(vec![font_face.clone()]);
+    let sheet_ref = manager.track_styles(&styles);
+
+    // the font face stays as long as the styles using it
+    let id = font_face.generate_key().unique_id();
+    drop(font_ref);
+    drop(other_ref);
+    assert!(bindings::has_style_sheet(&id));
+    drop(sheet_ref);
+    assert!(!bindings::has_style_sheet(&id));
+}
+
+#[wasm_bindgen_test]
+fn tracking_layer_order() {
+    let mut manager = StyleManager::default();
+    let styles = Styles::build(vec![RuleSet::build(vec![BackgroundColor(Color::hex(
+        0x000000,
+    ))])])
+    .in_layer("app");
+    let sheet_ref = manager.track_styles(&styles);
+
+    let order = LayerOrder::build(vec![LayerName::from("reset"), LayerName::from("app")]).unwrap();
+    let order_ref = manager.track_layer_order(&order);
+    assert!(Rc::ptr_eq(&order_ref, &manager.track_layer_order(&order)));
+
+    // the order is declared before the styles even though it was added later
+    let head = web_sys::window()
+        .unwrap()
+        .document()
+        .unwrap()
+        .head()
+        .unwrap();
+    let first = head.first_element_child().unwrap();
+    assert_eq!(first.id(), order_ref.id());
+
+    // later order declarations come after the earlier ones
+    let other_order =
+        LayerOrder::build(vec![LayerName::from("app"), LayerName::from("overrides")]).unwrap();
+    let other_order_ref = manager.track_layer_order(&other_order);
+    let first = head.first_element_child().unwrap();
+    assert_eq!(first.id(), order_ref.id());
+    assert_eq!(
+        first.next_element_sibling().unwrap().id(),
+        other_order_ref.id()
+    );
+
+    drop(other_order_ref);
+    drop(order_ref);
+    drop(sheet_ref);
+}
+
+#[wasm_bindgen_test]
+fn tracking_property() {
+    let mut manager = StyleManager::default();
+    let property = PropertyRule::<Length>::build("gap", false, Length::px(8)).unwrap();
+    let sheet_ref = manager.track_property(&property);
+    assert!(Rc::ptr_eq(&sheet_ref, &manager.track_property(&property)));
+    assert!(bindings::has_style_sheet(sheet_ref.id()));
+
+    let id = sheet_ref.id().to_owned();
+    drop(sheet_ref);
+    assert!(!bindings::has_style_sheet(&id));
+}
+
+#[wasm_bindgen_test]
+fn tracking_with_options() {
+    let mut manager = StyleManager::with_options(WriterOptions {
+        minify: true,
+        ..WriterOptions::default()
+    });
+    let property = PropertyRule::<Length>::build("indent", false, Length::em(0.5)).unwrap();
+    let sheet_ref = manager.track_property(&property);
+
+    let body = web_sys::window()
+        .unwrap()
+        .document()
+        .unwrap()
+        .get_element_by_id(sheet_ref.id())
+        .unwrap()
+        .text_content()
+        .unwrap();
+    assert!(body.contains(".5em"));
+    assert!(!body.contains("0.5em"));
+}
+
+const PRIMARY: ThemeToken<Color> = ThemeToken::new("primary");
+
+struct Palette(Color);
+impl Theme for Palette {
+    fn declarations(&self) -> Vec<Declaration> {
+        vec![PRIMARY.declare(self.0.clone()).into()]
+    }
+}
+
+#[wasm_bindgen_test]
+fn applying_theme() {
+    let mut manager = StyleManager::default();
+    let scope = ThemeScope::Root;
+    let light_ref = manager.apply_theme(&scope, &Palette(Color::hex(0xffffff)));
+    let dark_ref = manager.apply_theme(&scope, &Palette(Color::hex(0x000000)));
+    // the same sheet is updated in place
+    assert!(Rc::ptr_eq(&light_ref, &dark_ref));
+
+    let el = web_sys::window()
+        .unwrap()
+        .document()
+        .unwrap()
+        .get_element_by_id(dark_ref.id())
+        .unwrap();
+    assert_eq!(el.inner_html(), ":root{--primary:#000000;}");
+
+    let id = dark_ref.id().to_owned();
+    drop(light_ref);
+    drop(dark_ref);
+    assert!(!bindings::has_style_sheet(&id));
+}
@@ -0,0 +1,499 @@
+use russ::{
+    css::{multiple, props::*, values::*, CssProperty, CssWriter, Multiple, WriteValue},
+    CheckContrast, ContainerCondition, ContainerOrientation, ContainerQuery, ContainerSizeFeature,
+    ContrastLevel, CssKey, Declaration, DeclarationBlock, FontDisplay, FontFace, FontFaceSrc,
+    FontFaceStyle, FontFaceWeight, FontFormat, FontTech, Keyframe, KeyframeSelector, Keyframes,
+    LayerName, LayerOrder, PropertyRule, RuleSet, Styles, Theme, ThemeScope, ThemeToken,
+    UnicodeRange,
+};
+
+fn render_with(write: impl FnOnce(&mut CssWriter) -> std::io::Result<()>) -> String {
+    let mut v = Vec::new();
+    write(&mut CssWriter::new(&mut v)).expect("failed to write css");
+    String::from_utf8(v).expect("invalid utf8 returned")
+}
+
+#[test]
+fn keyframes() {
+    let keyframes = Keyframes::build(vec![
+        Keyframe::build(
+            KeyframeSelector::From,
+            vec![BackgroundColor(Color::hex(0xffffff))],
+        ),
+        Keyframe::build(
+            multiple![
+                KeyframeSelector::from(Percentage::from(50)),
+                KeyframeSelector::To
+            ],
+            vec![BackgroundColor(Color::hex(0x000000))],
+        ),
+    ]);
+    let name = render_with(|f| keyframes.name().write_value(f));
+    assert!(name.starts_with("kf-"));
+    assert_eq!(
+        render_with(|f| keyframes.write_css(f)),
+        format!(
+            "@keyframes {}{{from{{background-color:#FFFFFF;}}50%,to{{background-color:#000000;}}}}",
+            name
+        )
+    );
+
+    // same keyframes, same name
+    assert_eq!(keyframes.name(), keyframes.clone().name());
+    let other = Keyframes::build(vec![Keyframe::build(
+        KeyframeSelector::To,
+        vec![BackgroundColor(Color::hex(0x000000))],
+    )]);
+    assert_ne!(keyframes.name(), other.name());
+
+    assert_eq!(
+        render_with(|f| AnimationName(keyframes.name().into()).write_value(f)),
+        name
+    );
+    assert_eq!(
+        render_with(|f| AnimationName(AnimationNameValue::None).write_value(f)),
+        "none"
+    );
+    assert_eq!(
+        render_with(|f| AnimationName(
+            Multiple::build(vec![keyframes.name(), other.name()])
+                .unwrap()
+                .into()
+        )
+        .write_value(f)),
+        format!("{},{}", name, render_with(|f| other.name().write_value(f)))
+    );
+}
+
+#[test]
+fn font_face() {
+    let font_face = FontFace::build(
+        "Inter",
+        multiple![
+            FontFaceSrc::local("Inter"),
+            FontFaceSrc::Url(
+                Url::from("/fonts/inter.woff2"),
+                Some(FontFormat::Woff2),
+                Some(FontTech::Variations.into()),
+            ),
+            FontFaceSrc::url_format("/fonts/inter.woff", FontFormat::Woff)
+        ],
+    )
+    .with_weight(FontFaceWeight::range(100, 900))
+    .with_style(FontFaceStyle::Oblique(Some((
+        Angle::deg(0),
+        Some(Angle::deg(10)),
+    ))))
+    .with_display(FontDisplay::Swap)
+    .with_unicode_range(multiple![
+        UnicodeRange::range(0x0000, 0x00FF).unwrap(),
+        UnicodeRange::single(0x2122).unwrap()
+    ]);
+    assert_eq!(
+        render_with(|f| font_face.write_css(f)),
+        "@font-face{\
+         font-family:\"Inter\";\
+         src:local(\"Inter\"),url(\"/fonts/inter.woff2\") format(woff2) tech(variations),url(\"/fonts/inter.woff\") format(woff);\
+         font-weight:100 900;\
+         font-style:oblique 0deg 10deg;\
+         font-display:swap;\
+         unicode-range:U+0-FF,U+2122;\
+         }"
+    );
+
+    assert!(UnicodeRange::range(0x20, 0x10).is_none());
+    assert!(UnicodeRange::single(0x110000).is_none());
+
+    assert_eq!(
+        render_with(|f| FontFamily(multiple![
+            FontFamilyItem::from("Inter"),
+            GenericFamily::SansSerif.into()
+        ])
+        .write_value(f)),
+        "\"Inter\",sans-serif"
+    );
+}
+
+#[test]
+fn layer() {
+    let components = LayerName::from("components");
+    let order = LayerOrder::build(vec![
+        LayerName::from("reset"),
+        components.clone(),
+        components.nested("buttons"),
+        LayerName::from("app"),
+    ])
+    .unwrap();
+    assert_eq!(
+        render_with(|f| order.write_css(f)),
+        "@layer reset,components,components.buttons,app;"
+    );
+    assert!(LayerOrder::build(Vec::<LayerName>::new()).is_none());
+
+    let styles = Styles::build(vec![RuleSet::build(vec![BackgroundColor(Color::hex(
+        0xffffff,
+    ))])]);
+    let layered = styles.clone().in_layer(components);
+    assert_eq!(
+        render_with(|f| layered.write_css(f, "x")),
+        "@layer components{.x-0{background-color:#FFFFFF;}}"
+    );
+    assert_ne!(styles.generate_key(), layered.generate_key());
+}
+
+#[test]
+fn container() {
+    let wide = ContainerQuery::named("sidebar", ContainerSizeFeature::MinWidth(Length::px(400)));
+    let rule_set = RuleSet::build(vec![BackgroundColor(Color::hex(0xffffff))])
+        .with_container(wide, vec![BackgroundColor(Color::hex(0x000000))]);
+    assert_eq!(
+        render_with(|f| rule_set.write_rule_set(f, "x-0")),
+        ".x-0{background-color:#FFFFFF;}@container sidebar (min-width:400px){.x-0{background-color:#000000;}}"
+    );
+
+    let condition = ContainerCondition::from(ContainerSizeFeature::MinAspectRatio(Ratio(
+        16.into(),
+        9.into(),
+    )))
+    .and(ContainerSizeFeature::Orientation(
+        ContainerOrientation::Landscape,
+    ))
+    .and(
+        ContainerCondition::from(ContainerSizeFeature::MaxInlineSize(Length::px(800)))
+            .or(ContainerSizeFeature::Height(Length::px(0)))
+            .negate(),
+    );
+    assert_eq!(
+        render_with(|f| ContainerQuery::nearest(condition).write_value(f)),
+        "(min-aspect-ratio:16/9) and (orientation:landscape) and (not ((max-inline-size:800px) or (height:0px)))"
+    );
+
+    // a single condition isn't grouped and there must be at least one
+    assert!(ContainerCondition::all(Vec::<ContainerCondition>::new()).is_none());
+    assert!(ContainerCondition::any(Vec::<ContainerCondition>::new()).is_none());
+    assert_eq!(
+        ContainerCondition::all(vec![ContainerSizeFeature::Width(Length::px(0))]),
+        Some(ContainerCondition::from(ContainerSizeFeature::Width(
+            Length::px(0)
+        )))
+    );
+    assert_eq!(
+        render_with(|f| ContainerQuery::nearest(
+            ContainerCondition::any(vec![
+                ContainerSizeFeature::Width(Length::px(0)),
+                ContainerSizeFeature::Height(Length::px(0)),
+            ])
+            .unwrap()
+        )
+        .write_value(f)),
+        "(width:0px) or (height:0px)"
+    );
+
+    assert_eq!(
+        render_with(|f| Container(
+            ContainerName::from("sidebar"),
+            Some(ContainerType::InlineSize)
+        )
+        .write_value(f)),
+        "sidebar / inline-size"
+    );
+    assert_eq!(
+        render_with(|f| ContainerName(vec!["a".into(), "b".into()]).write_value(f)),
+        "a b"
+    );
+    assert_eq!(
+        render_with(|f| ContainerName::none().write_value(f)),
+        "none"
+    );
+}
+
+#[test]
+fn important() {
+    let normal = Declaration::from(BackgroundColor(Color::hex(0xffffff)));
+    let important = Declaration::important(BackgroundColor(Color::hex(0xffffff)));
+    assert!(!normal.is_important());
+    assert!(important.is_important());
+
+    let block = DeclarationBlock::build(vec![normal.clone(), important.clone()]);
+    assert_eq!(
+        render_with(|f| block.write_block(f)),
+        "{background-color:#FFFFFF;background-color:#FFFFFF!important;}"
+    );
+    assert_ne!(CssKey::new_hash(&normal), CssKey::new_hash(&important));
+}
+
+#[test]
+fn property() {
+    let property = PropertyRule::<Length>::build("--gap", false, Length::px(8)).unwrap();
+    assert_eq!(
+        render_with(|f| property.write_css(f)),
+        "@property --gap{syntax:\"<length>\";inherits:false;initial-value:8px;}"
+    );
+    assert_eq!(
+        render_with(|f| PropertyRule::<Multiple<Color>>::build(
+            "brand",
+            true,
+            Color::hex(0x336699)
+        )
+        .unwrap()
+        .write_css(f)),
+        "@property --brand{syntax:\"<color>#\";inherits:true;initial-value:#336699;}"
+    );
+    assert_eq!(
+        render_with(|f| DashedIdent::from("gap").write_value(f)),
+        "--gap"
+    );
+
+    // the initial value can't depend on the element
+    assert!(PropertyRule::<Length>::build("--size", false, property.var()).is_none());
+    assert!(PropertyRule::<Length>::build("--size", false, Length::em(2)).is_none());
+    assert!(PropertyRule::<Length>::build(
+        "--size",
+        false,
+        Length::px(8) + Length::from(property.var())
+    )
+    .is_none());
+    assert!(PropertyRule::<Length>::build("--size", false, Length::vw(50)).is_some());
+    assert!(PropertyRule::<Multiple<Color>>::build(
+        "--palette",
+        false,
+        Multiple::build(vec![
+            Color::hex(0x336699),
+            Color::from(
+                PropertyRule::<Color>::build("--brand", false, Color::hex(0x336699))
+                    .unwrap()
+                    .var()
+            ),
+        ])
+        .unwrap()
+    )
+    .is_none());
+    assert!(PropertyRule::<Image>::build(
+        "--backdrop",
+        false,
+        Gradient::linear(
+            None,
+            vec![(Color::hex(0xff0000), Length::em(1))],
+            Color::hex(0x0000ff)
+        )
+    )
+    .is_none());
+    assert!(PropertyRule::<Image>::build(
+        "--backdrop",
+        false,
+        Gradient::linear(
+            None,
+            vec![(Color::hex(0xff0000), Length::px(10))],
+            Color::hex(0x0000ff)
+        )
+    )
+    .is_some());
+}
+
+#[test]
+fn custom_property() {
+    let brand = CustomProperty::<Color>::build("brand", Color::hex(0x336699));
+    let block = DeclarationBlock::build(vec![
+        Declaration::from(brand.clone()),
+        Declaration::from(BackgroundColor(brand.var().into())),
+    ]);
+    assert_eq!(
+        render_with(|f| block.write_block(f)),
+        "{--brand:#336699;background-color:var(--brand);}"
+    );
+}
+
+const PRIMARY: ThemeToken<Color> = ThemeToken::new("primary");
+const SPACING: ThemeToken<Length> = ThemeToken::new("spacing");
+
+struct Palette {
+    primary: Color,
+    spacing: Length,
+}
+impl Theme for Palette {
+    fn declarations(&self) -> Vec<Declaration> {
+        vec![
+            PRIMARY.declare(self.primary.clone()).into(),
+            SPACING.declare(self.spacing.clone()).into(),
+        ]
+    }
+}
+
+#[test]
+fn theme() {
+    let light = Palette {
+        primary: Color::hex(0xffffff),
+        spacing: Length::px(8),
+    };
+    assert_eq!(
+        render_with(|f| ThemeScope::Root.write_theme(f, &light)),
+        ":root{--primary:#FFFFFF;--spacing:8px;}"
+    );
+    assert_eq!(
+        render_with(|f| ThemeScope::class("dark").write_theme(f, &light)),
+        ".dark{--primary:#FFFFFF;--spacing:8px;}"
+    );
+    assert_eq!(
+        render_with(|f| BackgroundColor(PRIMARY.var().into()).write_value(f)),
+        "var(--primary)"
+    );
+    assert_ne!(
+        ThemeScope::Root.generate_key(),
+        ThemeScope::class("dark").generate_key()
+    );
+}
+
+#[test]
+fn global_keywords() {
+    let block = DeclarationBlock::build(vec![
+        Declaration::from(BackgroundColor::inherit()),
+        Declaration::from(FontFamily::initial()),
+        Declaration::from(ContainerType::unset()),
+        Declaration::from(FontWeight::revert()),
+        Declaration::important(AnimationName::revert_layer()),
+    ]);
+    assert_eq!(
+        render_with(|f| block.write_block(f)),
+        "{background-color:inherit;font-family:initial;container-type:unset;font-weight:revert;animation-name:revert-layer!important;}"
+    );
+    assert_eq!(BackgroundColor::PROPERTY_NAME, "background-color");
+    assert_ne!(
+        CssKey::new_hash(Declaration::from(BackgroundColor::inherit())),
+        CssKey::new_hash(Declaration::from(FontFamily::inherit()))
+    );
+}
+
+#[test]
+fn transform() {
+    let block = DeclarationBlock::build(vec![
+        Declaration::from(Transform(TransformValue::List(multiple![
+            TransformFunction::translate_x(Length::px(10)),
+            TransformFunction::rotate(Angle::deg(45)),
+        ]))),
+        Declaration::from(TransformOrigin(
+            OriginPosition::xy(PositionHorizontalAnchor::Left, PositionVerticalAnchor::Top),
+            Some(Length::px(5)),
+        )),
+        Declaration::from(TransformStyle::Preserve3d),
+        Declaration::from(Perspective::from(Length::px(800))),
+        Declaration::from(PerspectiveOrigin(Position::center())),
+    ]);
+    assert_eq!(
+        render_with(|f| block.write_block(f)),
+        "{transform:translateX(10px) rotate(45deg);transform-origin:left top 5px;transform-style:preserve-3d;perspective:800px;perspective-origin:center;}"
+    );
+    assert_eq!(
+        render_with(|f| Transform(TransformValue::None).write_value(f)),
+        "none"
+    );
+    assert_eq!(
+        render_with(|f| Transform(TransformFunction::scale(2, None).into()).write_value(f)),
+        "scale(2)"
+    );
+    assert_eq!(
+        render_with(|f| TransformOrigin(OriginPosition::y(Length::px(5)), None).write_value(f)),
+        "center 5px"
+    );
+    assert_eq!(
+        render_with(|f| {
+            TransformOrigin(OriginPosition::y(PositionVerticalAnchor::Bottom), None).write_value(f)
+        }),
+        "bottom"
+    );
+}
+
+#[test]
+fn individual_transforms() {
+    let block = DeclarationBlock::build(vec![
+        Declaration::from(Translate::x(Percentage::from(50))),
+        Declaration::from(Rotate::from(Angle::deg(90))),
+        Declaration::from(Scale::uniform(2)),
+    ]);
+    assert_eq!(
+        render_with(|f| block.write_block(f)),
+        "{translate:50%;rotate:90deg;scale:2;}"
+    );
+
+    assert_eq!(
+        render_with(|f| Translate::xy(Length::px(10), Percentage::from(20)).write_value(f)),
+        "10px 20%"
+    );
+    assert_eq!(
+        render_with(|f| Translate::xyz(Length::px(1), Length::px(2), Length::px(3)).write_value(f)),
+        "1px 2px 3px"
+    );
+    assert_eq!(
+        render_with(|f| Rotate::from((RotateAxis::X, Angle::turn(0.5))).write_value(f)),
+        "x 0.5turn"
+    );
+    assert_eq!(
+        render_with(|f| Rotate::from((
+            RotateAxis::Vector(1.into(), 1.into(), 0.into()),
+            Angle::deg(45)
+        ))
+        .write_value(f)),
+        "1 1 0 45deg"
+    );
+    assert_eq!(
+        render_with(|f| Scale::xyz(1, Percentage::from(50), 2).write_value(f)),
+        "1 50% 2"
+    );
+    assert_eq!(render_with(|f| Scale::None.write_value(f)), "none");
+}
+
+#[test]
+fn contrast() {
+    let block = DeclarationBlock::build(vec![
+        Declaration::from(ForegroundColor(Color::hex(0x777777))),
+        BackgroundColor(Color::from(NamedColor::White)).into(),
+    ]);
+    assert_eq!(
+        render_with(|f| block.write_block(f)),
+        "{color:#777777;background-color:white;}"
+    );
+    let issues = block.check_contrast(ContrastLevel::Aa);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].foreground, Color::hex(0x777777));
+    assert_eq!(issues[0].min_ratio, 4.5);
+    assert!((issues[0].ratio - 4.48).abs() < 0.01);
+    assert!(block.check_contrast(ContrastLevel::AaLarge).is_empty());
+
+    // important declarations win over later ones
+    let block = DeclarationBlock::build(vec![
+        Declaration::important(ForegroundColor(Color::hex(0x000000))),
+        ForegroundColor(Color::hex(0x777777)).into(),
+        BackgroundColor(Color::hex(0xffffff)).into(),
+    ]);
+    assert!(block.check_contrast(ContrastLevel::Aaa).is_empty());
+
+    // colors which can't be resolved are skipped
+    let block = DeclarationBlock::build(vec![
+        Declaration::from(ForegroundColor(Color::from(Var::new("fg")))),
+        BackgroundColor(Color::hex(0xffffff)).into(),
+    ]);
+    assert!(block.check_contrast(ContrastLevel::Aaa).is_empty());
+
+    // container rules are checked against the colors they override
+    let rule_set = RuleSet::build(vec![
+        Declaration::from(ForegroundColor(Color::hex(0x000000))),
+        Background(
+            vec![],
+            BackgroundLayerFinal {
+                color: BackgroundColor(Color::hex(0xffffff)),
+                layer: Default::default(),
+            },
+        )
+        .into(),
+    ])
+    .with_container(
+        ContainerQuery::nearest(ContainerSizeFeature::MaxWidth(Length::px(400))),
+        vec![BackgroundColor(Color::hex(0x333333))],
+    );
+    let issues = rule_set.check_contrast(ContrastLevel::Aa);
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].background, Color::hex(0x333333));
+    assert_eq!(
+        Styles::build(vec![rule_set]).check_contrast(ContrastLevel::Aa),
+        issues
+    );
+}